@@ -0,0 +1,134 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ray_tracer::Vector3f;
+
+// The generic_array implementation from before vectors were stored in const generic arrays,
+// copied as it was apart from dropping the parts the benchmarks don't use. It collects
+// iterators into new arrays, allocates in cross and squares with powf.
+mod baseline {
+    use generic_array::{ArrayLength, GenericArray, typenum::U3};
+    use std::ops::{Mul, Div};
+    use std::iter::Sum;
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Vector<T, N: ArrayLength<T>> {
+        pub components: GenericArray<T, N>,
+    }
+
+    // Cloning GenericArray doesn't seem to work properly, so implement it manually:
+    impl<T: Clone, N: ArrayLength<T>> Clone for Vector<T, N> {
+        fn clone(&self) -> Self {
+            self.components.iter().cloned().into()
+        }
+    }
+
+    impl<T, N: ArrayLength<T>, I, X> From<I> for Vector<T, N>
+        where I: IntoIterator<Item=T, IntoIter=X>,
+              X: ExactSizeIterator<Item=T>,
+    {
+        fn from(iter: I) -> Self {
+            Self { components: GenericArray::from_exact_iter(iter).unwrap() }
+        }
+    }
+
+    impl <T: Clone, N: ArrayLength<T>> From<&Vector<T, N>> for Vector<T, N> {
+        fn from(vector: &Vector<T, N>) -> Self {
+            vector.clone()
+        }
+    }
+
+    impl<T: Into<f64> + Copy, D: Into<f64>, N> Div<D> for &Vector<T, N>
+        where N: ArrayLength<T> + ArrayLength<f64>,
+    {
+        type Output = Vector<f64, N>;
+
+        fn div(self, divisor: D) -> Self::Output {
+            let inverse = divisor.into().recip();
+
+            self.components.iter().map(|&a| a.into() * inverse).into()
+        }
+    }
+
+    impl<T: Mul<Output=T> + Sum + Copy, N: ArrayLength<T>> Vector<T, N> {
+        pub fn dot<S: Into<Self>>(&self, other: S) -> T {
+            let other: Self = other.into();
+
+            self.components.iter()
+                .zip(other.components.iter())
+                .map(|(&a, &b)| a * b)
+                .sum()
+        }
+    }
+
+    impl<T: Into<f64> + Copy, N: ArrayLength<T>> Vector<T, N> {
+        pub fn length_squared(&self) -> f64 {
+            self.components.iter().map(|&a| a.into().powf(2.0)).sum()
+        }
+
+        pub fn length(&self) -> f64 {
+            self.length_squared().sqrt()
+        }
+    }
+
+    impl<T: Into<f64> + Copy, N: ArrayLength<T> + ArrayLength<f64>> Vector<T, N> {
+        pub fn normalize(&self) -> Vector<f64, N> {
+            self / self.length()
+        }
+    }
+
+    pub type Vector3<T> = Vector<T, U3>;
+
+    impl<T: Copy> Vector3<T> {
+        pub fn new(x: T, y: T, z: T) -> Self {
+            // The original used arr![T; x, y, z], which no longer compiles for a generic T.
+            Self { components: [x, y, z].into() }
+        }
+    }
+
+    impl<T: Into<f64> + Copy> Vector3<T> {
+        pub fn cross(&self, other: &Self) -> Vector3f {
+            let v1 = self.components.iter().map(|&a| a.into()).collect::<Vec<f64>>();
+            let v2 = other.components.iter().map(|&b| b.into()).collect::<Vec<f64>>();
+
+            let x = (v1[1] * v2[2]) - (v1[2] * v2[1]);
+            let y = (v1[2] * v2[0]) - (v1[0] * v2[2]);
+            let z = (v1[0] * v2[1]) - (v1[1] * v2[0]);
+
+            Vector3f::new(x, y, z)
+        }
+    }
+
+    pub type Vector3f = Vector3<f64>;
+}
+
+fn cross(c: &mut Criterion) {
+    let (a, b) = (Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(4.0, 5.0, 6.0));
+    let (a0, b0) = (baseline::Vector3f::new(1.0, 2.0, 3.0), baseline::Vector3f::new(4.0, 5.0, 6.0));
+
+    let mut group = c.benchmark_group("cross");
+    group.bench_function("const_generic", |bench| bench.iter(|| black_box(a).cross(&black_box(b))));
+    group.bench_function("generic_array", |bench| bench.iter(|| black_box(&a0).cross(black_box(&b0))));
+    group.finish();
+}
+
+fn dot(c: &mut Criterion) {
+    let (a, b) = (Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(4.0, 5.0, 6.0));
+    let (a0, b0) = (baseline::Vector3f::new(1.0, 2.0, 3.0), baseline::Vector3f::new(4.0, 5.0, 6.0));
+
+    let mut group = c.benchmark_group("dot");
+    group.bench_function("const_generic", |bench| bench.iter(|| black_box(a).dot(black_box(b))));
+    group.bench_function("generic_array", |bench| bench.iter(|| black_box(&a0).dot(black_box(&b0))));
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let a = Vector3f::new(1.0, 2.0, 3.0);
+    let a0 = baseline::Vector3f::new(1.0, 2.0, 3.0);
+
+    let mut group = c.benchmark_group("normalize");
+    group.bench_function("const_generic", |bench| bench.iter(|| black_box(a).normalize()));
+    group.bench_function("generic_array", |bench| bench.iter(|| black_box(&a0).normalize()));
+    group.finish();
+}
+
+criterion_group!(benches, cross, dot, normalize);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+use super::matrix4x4::{Matrix4x4, SingularMatrix};
+use super::transform::Transform;
+use super::quaternion::Quaternion;
+use super::point3::Point3f;
+use super::vector3::Vector3f;
+use super::ray::Ray;
+use super::ray_differential::RayDifferential;
+use super::bounds3::Bounds3f;
+use super::float::Real;
+
+// The number of times that motion_bounds samples the transformation over the interval.
+const MOTION_SAMPLES: usize = 64;
+
+// Interpolates between two transforms over a time interval for motion blur. Each
+// transform is decomposed into translation, rotation and scale which are interpolated
+// separately so that rotations don't shear as they would if the matrices were lerped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimatedTransform {
+    pub start_transform: Transform,
+    pub end_transform: Transform,
+    pub start_time: Real,
+    pub end_time: Real,
+
+    actually_animated: bool,
+    has_rotation: bool,
+    t: [Vector3f; 2],
+    r: [Quaternion; 2],
+    s: [Matrix4x4; 2],
+}
+
+impl AnimatedTransform {
+    pub fn new(start_transform: &Transform, start_time: Real, end_transform: &Transform, end_time: Real) -> Result<Self, SingularMatrix> {
+        let (t0, r0, s0) = decompose(&start_transform.m)?;
+        let (t1, mut r1, s1) = decompose(&end_transform.m)?;
+
+        // Flip the second rotation if needed so that slerp takes the shortest path.
+        if r0.dot(&r1) < 0.0 {
+            r1 = -r1;
+        }
+
+        // Any difference counts as rotation, even if it's small enough for slerp to lerp,
+        // since the corners of a box far from the axis still swing outside the end bounds.
+        let actually_animated = start_transform != end_transform;
+        let has_rotation = r0 != r1;
+
+        Ok(Self {
+            start_transform: *start_transform,
+            end_transform: *end_transform,
+            start_time,
+            end_time,
+            actually_animated,
+            has_rotation,
+            t: [t0, t1],
+            r: [r0, r1],
+            s: [s0, s1],
+        })
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.actually_animated
+    }
+
+    pub fn has_rotation(&self) -> bool {
+        self.has_rotation
+    }
+
+    // Times outside the interval are clamped to the start or end transform.
+    // Fails if the interpolated scale is singular, since the transform has no inverse there.
+    pub fn interpolate(&self, time: Real) -> Result<Transform, SingularMatrix> {
+        if !self.actually_animated || time <= self.start_time {
+            return Ok(self.start_transform);
+        }
+
+        if time >= self.end_time {
+            return Ok(self.end_transform);
+        }
+
+        let dt = (time - self.start_time) / (self.end_time - self.start_time);
+
+        let translation = self.t[0] * (1.0 - dt) + self.t[1] * dt;
+        let rotation = self.r[0].slerp(&self.r[1], dt);
+
+        let mut scale = [[0.0; 4]; 4];
+
+        for (i, row) in scale.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.s[0].m[i][j] * (1.0 - dt) + self.s[1].m[i][j] * dt;
+            }
+        }
+
+        let scale = Transform::new(Matrix4x4::new(scale))?;
+        let translate = Transform::translate(&translation);
+        let rotate = rotation.to_transform();
+
+        Ok(&(&translate * &rotate) * &scale)
+    }
+
+    pub fn transform_point(&self, time: Real, p: &Point3f) -> Result<Point3f, SingularMatrix> {
+        Ok(&self.interpolate(time)? * p)
+    }
+
+    pub fn transform_vector(&self, time: Real, v: &Vector3f) -> Result<Vector3f, SingularMatrix> {
+        Ok(&self.interpolate(time)? * v)
+    }
+
+    pub fn transform_ray(&self, ray: &Ray) -> Result<Ray, SingularMatrix> {
+        Ok(&self.interpolate(ray.time)? * ray)
+    }
+
+    pub fn transform_ray_differential(&self, rd: &RayDifferential) -> Result<RayDifferential, SingularMatrix> {
+        Ok(&self.interpolate(rd.ray.time)? * rd)
+    }
+
+    // Returns a box that contains the given box at every moment over the time interval.
+    pub fn motion_bounds(&self, bounds: &Bounds3f) -> Result<Bounds3f, SingularMatrix> {
+        if !self.actually_animated {
+            return Ok(&self.start_transform * bounds);
+        }
+
+        // Without rotation, points move in straight lines so the end points are enough.
+        if !self.has_rotation {
+            return Ok((&self.start_transform * bounds).union(&(&self.end_transform * bounds)));
+        }
+
+        // Otherwise, sample the transformation over the interval and pad each sample by
+        // how far the corners can move before the nearest sample. The transformed box is
+        // the convex hull of its corners, so this contains the whole swept volume.
+        let padding = (0..8)
+            .map(|i| self.max_corner_displacement(&bounds.corner(i)))
+            .fold(0.0, Real::max) / (2.0 * MOTION_SAMPLES as Real);
+
+        let duration = self.end_time - self.start_time;
+
+        (0..=MOTION_SAMPLES)
+            .map(|i| self.start_time + duration * i as Real / MOTION_SAMPLES as Real)
+            .try_fold(Bounds3f::default(), |a, time| Ok(a.union(&(&self.interpolate(time)? * bounds))))
+            .map(|b| b.expand(padding))
+    }
+
+    // An upper bound on the total distance the point travels over the time interval.
+    fn max_corner_displacement(&self, p: &Point3f) -> Real {
+        let scaled_start = linear_part(&self.s[0], p);
+        let scaled_end = linear_part(&self.s[1], p);
+
+        // The slerp rotates about a fixed axis at twice the angle between the quaternions.
+        let angle = self.r[0].dot(&self.r[1]).clamp(-1.0, 1.0).acos() * 2.0;
+        let radius = scaled_start.length().max(scaled_end.length());
+
+        let translation = (self.t[1] - self.t[0]).length();
+        let scaling = (scaled_end - scaled_start).length();
+
+        translation + angle * radius + scaling
+    }
+}
+
+fn linear_part(m: &Matrix4x4, p: &Point3f) -> Vector3f {
+    let m = &m.m;
+    let (x, y, z) = (p.x(), p.y(), p.z());
+
+    Vector3f::new(
+        m[0][0] * x + m[0][1] * y + m[0][2] * z,
+        m[1][0] * x + m[1][1] * y + m[1][2] * z,
+        m[2][0] * x + m[2][1] * y + m[2][2] * z,
+    )
+}
+
+// Splits the matrix into a translation, followed by a rotation, followed by a scale.
+// The rotation is found by polar decomposition, i.e. repeatedly averaging the matrix
+// with its inverse transpose until it converges.
+fn decompose(m: &Matrix4x4) -> Result<(Vector3f, Quaternion, Matrix4x4), SingularMatrix> {
+    let translation = Vector3f::new(m.m[0][3], m.m[1][3], m.m[2][3]);
+
+    let mut linear = *m;
+
+    for i in 0..3 {
+        linear.m[i][3] = 0.0;
+        linear.m[3][i] = 0.0;
+    }
+
+    linear.m[3][3] = 1.0;
+
+    let mut r = linear;
+
+    for _ in 0..100 {
+        let r_it = r.transpose().inverse()?;
+        let mut r_next = [[0.0; 4]; 4];
+        let mut norm: Real = 0.0;
+
+        for (i, row) in r_next.iter_mut().enumerate() {
+            let mut row_sum = 0.0;
+
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = 0.5 * (r.m[i][j] + r_it.m[i][j]);
+                row_sum += (r.m[i][j] - *value).abs();
+            }
+
+            norm = norm.max(row_sum);
+        }
+
+        r = Matrix4x4::new(r_next);
+
+        if norm <= 0.0001 {
+            break;
+        }
+    }
+
+    let rotation = Quaternion::from(&Transform::from_matrices(r, r.transpose()));
+    let scale = &r.inverse()? * &linear;
+
+    Ok((translation, rotation, scale))
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,253 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = AnimatedTransform;
+
+fn translating() -> Subject {
+    let start = Transform::translate(&Vector3f::new(0.0, 0.0, 0.0));
+    let end = Transform::translate(&Vector3f::new(10.0, 0.0, 0.0));
+
+    Subject::new(&start, 1.0, &end, 2.0).unwrap()
+}
+
+// Less than a half turn, which would be ambiguous once rounded to f32.
+fn rotating() -> Subject {
+    let start = Transform::rotate_z(0.0);
+    let end = Transform::rotate_z(120.0);
+
+    Subject::new(&start, 0.0, &end, 1.0).unwrap()
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_an_animated_transform_and_sets_its_fields() {
+        let subject = translating();
+
+        assert_eq!(subject.start_time, 1.0);
+        assert_eq!(subject.end_time, 2.0);
+        assert!(subject.is_animated());
+        assert!(!subject.has_rotation());
+    }
+
+    #[test]
+    fn it_is_not_animated_if_the_transforms_are_the_same() {
+        let transform = Transform::scale(1.0, 2.0, 3.0);
+        let subject = Subject::new(&transform, 0.0, &transform, 1.0).unwrap();
+
+        assert!(!subject.is_animated());
+    }
+
+    #[test]
+    fn it_detects_rotation_between_the_transforms() {
+        assert!(rotating().has_rotation());
+    }
+
+    #[test]
+    fn it_returns_an_error_if_a_transform_cannot_be_decomposed() {
+        let start = Transform::default();
+        let end = Transform::from_matrices(Matrix4x4::new([[0.0; 4]; 4]), Matrix4x4::identity());
+
+        assert_eq!(Subject::new(&start, 0.0, &end, 1.0), Err(SingularMatrix));
+    }
+}
+
+mod interpolate {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_end_transforms_outside_the_interval() {
+        let subject = translating();
+
+        assert_eq!(subject.interpolate(0.0), Ok(subject.start_transform));
+        assert_eq!(subject.interpolate(3.0), Ok(subject.end_transform));
+    }
+
+    #[test]
+    fn it_linearly_interpolates_the_translation() {
+        let subject = translating().interpolate(1.25).unwrap();
+
+        assert_approx!(subject.m, Transform::translate(&Vector3f::new(2.5, 0.0, 0.0)).m, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_interpolates_the_rotation_without_shearing() {
+        let subject = rotating().interpolate(0.5).unwrap();
+
+        assert_approx!(subject, Transform::rotate_z(60.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_interpolates_translation_rotation_and_scale_together() {
+        let start = Transform::default();
+        let end = &(&Transform::translate(&Vector3f::new(4.0, 0.0, 0.0)) * &Transform::rotate_x(90.0)) * &Transform::scale(3.0, 3.0, 3.0);
+
+        let subject = Subject::new(&start, 0.0, &end, 1.0).unwrap().interpolate(0.5).unwrap();
+        let expected = &(&Transform::translate(&Vector3f::new(2.0, 0.0, 0.0)) * &Transform::rotate_x(45.0)) * &Transform::scale(2.0, 2.0, 2.0);
+
+        assert_approx!(subject, expected, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_reproduces_the_end_transforms_at_the_end_points_of_the_decomposition() {
+        let start = &Transform::translate(&Vector3f::new(1.0, 2.0, 3.0)) * &Transform::rotate(30.0, &Vector3f::new(1.0, 1.0, 0.0));
+        let end = &Transform::rotate_y(-60.0) * &Transform::scale(1.0, 2.0, 0.5);
+
+        let subject = Subject::new(&start, 0.0, &end, 1.0).unwrap();
+
+        assert_approx!(subject.interpolate(0.0 + 1e-12).unwrap().m, start.m, epsilon = 1e-6);
+        assert_approx!(subject.interpolate(1.0 - 1e-12).unwrap().m, end.m, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_interpolated_scale_is_singular() {
+        let mut subject = translating();
+        subject.s[1] = Transform::scale(-1.0, 1.0, 1.0).m;
+
+        assert_eq!(subject.interpolate(1.5), Err(SingularMatrix));
+        assert!(subject.interpolate(1.25).is_ok());
+    }
+}
+
+mod transform_point {
+    use super::*;
+
+    #[test]
+    fn it_transforms_the_point_at_the_given_time() {
+        let subject = translating();
+        let point = subject.transform_point(1.5, &Point3f::new(1.0, 1.0, 1.0)).unwrap();
+
+        assert_approx!(point, Point3f::new(6.0, 1.0, 1.0), epsilon = 1e-6);
+    }
+}
+
+mod transform_vector {
+    use super::*;
+
+    #[test]
+    fn it_transforms_the_vector_at_the_given_time() {
+        let subject = rotating();
+        let vector = subject.transform_vector(0.5, &Vector3f::new(1.0, 0.0, 0.0)).unwrap();
+
+        assert_approx!(vector, Vector3f::new(0.5, (3.0 as Real).sqrt() / 2.0, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod transform_ray {
+    use super::*;
+
+    #[test]
+    fn it_transforms_the_ray_at_the_rays_time() {
+        let subject = translating();
+        let ray = Ray::new(Point3f::new(0.0, 0.0, 0.0), Vector3f::new(0.0, 1.0, 0.0), None, Some(1.5), None);
+
+        let transformed = subject.transform_ray(&ray).unwrap();
+
+        assert_approx!(transformed.o, Point3f::new(5.0, 0.0, 0.0), epsilon = 1e-6);
+        assert_approx!(transformed.d, Vector3f::new(0.0, 1.0, 0.0), epsilon = 1e-6);
+        assert_eq!(transformed.time, 1.5);
+    }
+
+    #[test]
+    fn it_transforms_ray_differentials_at_the_rays_time() {
+        let subject = rotating();
+
+        let mut rd = RayDifferential::new(Point3f::new(1.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), None, Some(0.5), None);
+        rd.has_differentials = true;
+        rd.rx_origin = Point3f::new(2.0, 0.0, 0.0);
+        rd.ry_direction = Vector3f::new(0.0, 1.0, 0.0);
+
+        let transformed = subject.transform_ray_differential(&rd).unwrap();
+
+        assert!(transformed.has_differentials);
+        assert_approx!(transformed.ray.d, Vector3f::new(0.5, (3.0 as Real).sqrt() / 2.0, 0.0), epsilon = 1e-6);
+        assert_approx!(transformed.rx_origin, Point3f::new(1.0, (3.0 as Real).sqrt(), 0.0), epsilon = 1e-6);
+        assert_approx!(transformed.ry_direction, Vector3f::new(-(3.0 as Real).sqrt() / 2.0, 0.5, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod motion_bounds {
+    use super::*;
+
+    fn unit_box() -> Bounds3f {
+        Bounds3f::new(&Point3f::new(0.0, 0.0, 0.0), &Point3f::new(1.0, 1.0, 1.0))
+    }
+
+    fn assert_contains(outer: &Bounds3f, inner: &Bounds3f) {
+        assert_eq!(&outer.union(inner), outer);
+    }
+
+    #[test]
+    fn it_returns_the_transformed_box_if_not_animated() {
+        let transform = Transform::translate(&Vector3f::new(1.0, 0.0, 0.0));
+        let subject = Subject::new(&transform, 0.0, &transform, 1.0).unwrap();
+
+        assert_eq!(subject.motion_bounds(&unit_box()), Ok(&transform * &unit_box()));
+    }
+
+    #[test]
+    fn it_returns_the_union_of_the_end_points_for_translation() {
+        let bounds = translating().motion_bounds(&unit_box()).unwrap();
+
+        assert_approx!(bounds.p_min, Point3f::new(0.0, 0.0, 0.0), epsilon = 1e-6);
+        assert_approx!(bounds.p_max, Point3f::new(11.0, 1.0, 1.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_contains_the_box_at_every_moment_while_rotating() {
+        let subject = rotating();
+        let bounds = subject.motion_bounds(&unit_box()).unwrap();
+
+        for i in 0..=1000 {
+            let time = i as Real / 1000.0;
+            let moving = &subject.interpolate(time).unwrap() * &unit_box();
+
+            assert_contains(&bounds, &moving);
+        }
+    }
+
+    #[test]
+    fn it_is_reasonably_tight_while_rotating() {
+        let bounds = rotating().motion_bounds(&unit_box()).unwrap();
+        let radius = (2.0 as Real).sqrt();
+
+        assert!(bounds.p_min.x() > -radius - 0.1);
+        assert!(bounds.p_max.y() < radius + 0.1);
+    }
+
+    #[test]
+    fn it_contains_the_box_when_everything_changes_at_once() {
+        let start = Transform::rotate(-30.0, &Vector3f::new(1.0, 2.0, 3.0));
+        let end = &(&Transform::translate(&Vector3f::new(3.0, -2.0, 1.0)) * &Transform::rotate(150.0, &Vector3f::new(-1.0, 0.0, 1.0))) * &Transform::scale(2.0, 0.5, 1.0);
+
+        let subject = Subject::new(&start, 0.0, &end, 1.0).unwrap();
+        let bounds = subject.motion_bounds(&unit_box()).unwrap();
+
+        for i in 0..=1000 {
+            let time = i as Real / 1000.0;
+            let moving = &subject.interpolate(time).unwrap() * &unit_box();
+
+            assert_contains(&bounds, &moving);
+        }
+    }
+
+    #[test]
+    fn it_contains_the_box_while_rotating_by_a_small_angle_far_from_the_axis() {
+        let bounds = Bounds3f::new(&Point3f::new(99.0, 0.0, 0.0), &Point3f::new(100.0, 1.0, 1.0));
+
+        for &degrees in [1.0, 2.0, 3.5].iter() {
+            let subject = Subject::new(&Transform::rotate_z(0.0), 0.0, &Transform::rotate_z(degrees), 1.0).unwrap();
+            let motion_bounds = subject.motion_bounds(&bounds).unwrap();
+
+            assert!(subject.has_rotation());
+
+            for i in 0..=1000 {
+                let time = i as Real / 1000.0;
+                let moving = &subject.interpolate(time).unwrap() * &bounds;
+
+                assert_contains(&motion_bounds, &moving);
+            }
+        }
+    }
+}
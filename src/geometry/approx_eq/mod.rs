@@ -0,0 +1,109 @@
+use super::float::Float;
+
+// How close two values need to be to be considered equal. Relative and Ulps comparisons
+// don't work well near zero, so compare values that should be zero with Absolute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance<F> {
+    Absolute(F),
+    Relative(F),
+    Ulps(u32),
+}
+
+impl<F> Default for Tolerance<F> {
+    fn default() -> Self {
+        Tolerance::Ulps(4)
+    }
+}
+
+// Compares values that may differ by floating point error. Types made up of several floats
+// are approximately equal if all of their floats are, e.g. each component of a point.
+pub trait ApproxEq {
+    type Float: Float;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Float>) -> bool;
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Float) -> bool {
+        self.approx_eq(other, Tolerance::Absolute(epsilon))
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: Self::Float) -> bool {
+        self.approx_eq(other, Tolerance::Relative(max_relative))
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.approx_eq(other, Tolerance::Ulps(max_ulps))
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($t:ident) => {
+        impl ApproxEq for $t {
+            type Float = $t;
+
+            fn approx_eq(&self, other: &Self, tolerance: Tolerance<$t>) -> bool {
+                let (a, b) = (*self, *other);
+
+                // Handles infinities, which would otherwise have a difference of NaN.
+                if a == b {
+                    return true;
+                }
+
+                if !a.is_finite() || !b.is_finite() {
+                    return false;
+                }
+
+                let difference = (a - b).abs();
+
+                match tolerance {
+                    Tolerance::Absolute(epsilon) => difference <= epsilon,
+                    Tolerance::Relative(max_relative) => {
+                        difference <= a.abs().max(b.abs()) * max_relative
+                    },
+                    Tolerance::Ulps(max_ulps) => {
+                        // Floats of the same sign are ordered the same way as their bits.
+                        let same_sign = a.is_sign_negative() == b.is_sign_negative();
+                        let ulps = a.to_bits().abs_diff(b.to_bits());
+
+                        same_sign && u64::from(ulps) <= u64::from(max_ulps)
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_approx_eq!(f32);
+impl_approx_eq!(f64);
+
+// Panics and prints both values if they aren't approximately equal. The tolerance defaults
+// to 4 ulps and can be given as `epsilon = ...`, `max_relative = ...` or `max_ulps = ...`.
+#[macro_export]
+macro_rules! assert_approx {
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_approx!($left, $right, $crate::geometry::Tolerance::Absolute($epsilon))
+    };
+    ($left:expr, $right:expr, max_relative = $max_relative:expr $(,)?) => {
+        $crate::assert_approx!($left, $right, $crate::geometry::Tolerance::Relative($max_relative))
+    };
+    ($left:expr, $right:expr, max_ulps = $max_ulps:expr $(,)?) => {
+        $crate::assert_approx!($left, $right, $crate::geometry::Tolerance::Ulps($max_ulps))
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx!($left, $right, $crate::geometry::Tolerance::default())
+    };
+    ($left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::geometry::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion failed: `left ≈ right` with {:?}\n  left: `{:?}`\n right: `{:?}`",
+                        tolerance, left, right,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,100 @@
+use super::*;
+
+mod absolute {
+    use super::*;
+
+    #[test]
+    fn it_compares_the_difference_with_the_epsilon() {
+        assert!(1.0.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0.abs_diff_eq(&1.2, 0.1));
+    }
+
+    #[test]
+    fn it_treats_infinities_of_the_same_sign_as_equal() {
+        assert!(f64::INFINITY.abs_diff_eq(&f64::INFINITY, 0.1));
+        assert!(!f64::INFINITY.abs_diff_eq(&f64::NEG_INFINITY, 0.1));
+        assert!(!f64::INFINITY.abs_diff_eq(&f64::MAX, 0.1));
+    }
+
+    #[test]
+    fn it_never_treats_nan_as_equal() {
+        assert!(!f64::NAN.abs_diff_eq(&f64::NAN, 0.1));
+        assert!(!f64::NAN.abs_diff_eq(&1.0, f64::INFINITY));
+    }
+}
+
+mod relative {
+    use super::*;
+
+    #[test]
+    fn it_scales_the_tolerance_by_the_larger_value() {
+        assert!(1000.0.relative_eq(&1001.0, 0.01));
+        assert!(!1.0.relative_eq(&1.1, 0.01));
+    }
+
+    #[test]
+    fn it_does_not_treat_tiny_values_as_equal_to_zero() {
+        assert!(!0.0.relative_eq(&1e-17, 1e-6));
+        assert!(0.0.abs_diff_eq(&1e-17, 1e-6));
+    }
+}
+
+mod ulps {
+    use super::*;
+
+    #[test]
+    fn it_counts_the_representable_floats_between_the_values() {
+        let a = 1.0_f64;
+        let b = a.next_float_up().next_float_up();
+
+        assert!(a.ulps_eq(&b, 2));
+        assert!(!a.ulps_eq(&b, 1));
+    }
+
+    #[test]
+    fn it_works_for_single_precision_floats() {
+        let a = 1.0_f32;
+        let b = a.next_float_up();
+
+        assert!(a.ulps_eq(&b, 1));
+        assert!(!a.ulps_eq(&(a + 0.001), 4));
+    }
+
+    #[test]
+    fn it_does_not_treat_values_with_different_signs_as_equal() {
+        assert!(!1e-10.ulps_eq(&-1e-10, u32::MAX));
+    }
+
+    #[test]
+    fn it_does_not_treat_tiny_values_within_epsilon_as_equal() {
+        assert!(!1e-17.ulps_eq(&2e-17, 0));
+    }
+
+    #[test]
+    fn it_treats_positive_and_negative_zero_as_equal() {
+        assert!(0.0.ulps_eq(&-0.0, 0));
+    }
+}
+
+mod assert_approx {
+    use super::*;
+
+    #[test]
+    fn it_passes_if_the_values_are_within_4_ulps_by_default() {
+        assert_approx!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn it_accepts_each_kind_of_tolerance() {
+        assert_approx!(1.0, 1.05, epsilon = 0.1);
+        assert_approx!(1000.0, 1001.0, max_relative = 0.01);
+        assert_approx!(1.0, 1.0_f64.next_float_up(), max_ulps = 1);
+        assert_approx!(1.0, 1.05, Tolerance::Absolute(0.1));
+    }
+
+    #[test]
+    #[should_panic(expected = "left: `1.0`\n right: `1.2`")]
+    fn it_prints_both_values_if_they_are_not_equal() {
+        assert_approx!(1.0, 1.2, epsilon = 0.1);
+    }
+}
@@ -1,13 +1,185 @@
-use generic_array::ArrayLength;
+use std::ops::{Add, Sub, Index};
 use super::point::Point;
+use super::vector::Vector;
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::float::{Float, Scalar};
 
-pub struct Bounds<T, N: ArrayLength<T>> {
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds<T, const N: usize> {
     pub p_min: Point<T, N>,
     pub p_max: Point<T, N>,
 }
 
-impl<T: Clone, N: ArrayLength<T>> From<&Point<T, N>> for Bounds<T, N> {
+impl<T: Clone, const N: usize> From<&Point<T, N>> for Bounds<T, N> {
     fn from(point: &Point<T, N>) -> Self {
         Self { p_min: point.clone(), p_max: point.clone() }
     }
 }
+
+// Allows the bounds to be indexed by a ray's direction signs, e.g. bounds[1] is p_max.
+impl<T, const N: usize> Index<usize> for Bounds<T, N> {
+    type Output = Point<T, N>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.p_min,
+            1 => &self.p_max,
+            _ => panic!("bounds index out of range: {}", index),
+        }
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for Bounds<T, N> {
+    type Float = T::Float;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Float>) -> bool {
+        self.p_min.approx_eq(&other.p_min, tolerance) && self.p_max.approx_eq(&other.p_max, tolerance)
+    }
+}
+
+// Serialized as the pair of corners, e.g. [[0.0, 0.0], [1.0, 1.0]].
+#[cfg(feature = "serde")]
+impl<T: Serialize, const N: usize> Serialize for Bounds<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.p_min, &self.p_max).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Bounds<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (p_min, p_max) = Deserialize::deserialize(deserializer)?;
+
+        Ok(Self { p_min, p_max })
+    }
+}
+
+impl<T: PartialOrd + Copy, const N: usize> Bounds<T, N> {
+    pub fn new(p1: &Point<T, N>, p2: &Point<T, N>) -> Self {
+        Self { p_min: p1.min(p2), p_max: p1.max(p2) }
+    }
+
+    pub fn union_point(&self, point: &Point<T, N>) -> Self {
+        let p_min = self.p_min.min(point);
+        let p_max = self.p_max.max(point);
+
+        Self { p_min, p_max }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let p_min = self.p_min.min(&other.p_min);
+        let p_max = self.p_max.max(&other.p_max);
+
+        Self { p_min, p_max }
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        let p_min = self.p_min.max(&other.p_min);
+        let p_max = self.p_max.min(&other.p_max);
+
+        Self { p_min, p_max }
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (0..N).all(|i| {
+            self.p_max.components[i] >= other.p_min.components[i] &&
+            self.p_min.components[i] <= other.p_max.components[i]
+        })
+    }
+
+    pub fn inside(&self, point: &Point<T, N>) -> bool {
+        (0..N).all(|i| {
+            point.components[i] >= self.p_min.components[i] &&
+            point.components[i] <= self.p_max.components[i]
+        })
+    }
+
+    // Doesn't consider points on the upper boundary to be inside, e.g. for integer pixel bounds.
+    pub fn inside_exclusive(&self, point: &Point<T, N>) -> bool {
+        (0..N).all(|i| {
+            point.components[i] >= self.p_min.components[i] &&
+            point.components[i] < self.p_max.components[i]
+        })
+    }
+
+    pub fn corner(&self, index: usize) -> Point<T, N> {
+        Point::generate(|i| match index & (1 << i) {
+            0 => self.p_min.components[i],
+            _ => self.p_max.components[i],
+        })
+    }
+}
+
+impl<T: PartialOrd + Sub<Output=T> + Default + Copy, const N: usize> Bounds<T, N> {
+    // Axes where the box is empty have a length of zero, e.g. for the default box, rather
+    // than a negative length that would overflow for integer bounds.
+    pub fn diagonal(&self) -> Vector<T, N> {
+        Vector::generate(|i| {
+            let (min, max) = (self.p_min.components[i], self.p_max.components[i]);
+
+            if max > min { max - min } else { T::default() }
+        })
+    }
+
+    pub fn maximum_extent(&self) -> usize {
+        let diagonal = self.diagonal();
+        let components = &diagonal.components;
+
+        (1..N).fold(0, |best, i| {
+            if components[i] > components[best] { i } else { best }
+        })
+    }
+}
+
+impl<T, const N: usize> Bounds<T, N>
+    where T: Add<Output=T> + Sub<Output=T> + Copy,
+{
+    pub fn expand(&self, delta: T) -> Self {
+        let p_min = Point::generate(|i| self.p_min.components[i] - delta);
+        let p_max = Point::generate(|i| self.p_max.components[i] + delta);
+
+        Self { p_min, p_max }
+    }
+}
+
+impl<T: Scalar, const N: usize> Bounds<T, N> {
+    pub fn lerp(&self, t: &Point<T::Float, N>) -> Point<T::Float, N> {
+        Point::generate(|i| {
+            let a = self.p_min.components[i].to_float();
+            let b = self.p_max.components[i].to_float();
+
+            a * (T::Float::ONE - t.components[i]) + b * t.components[i]
+        })
+    }
+
+    // Returns the position of the point relative to the corners, e.g. p_min is (0, 0, 0).
+    pub fn offset(&self, point: &Point<T, N>) -> Vector<T::Float, N> {
+        Vector::generate(|i| {
+            let a = self.p_min.components[i].to_float();
+            let b = self.p_max.components[i].to_float();
+            let p = point.components[i].to_float();
+
+            if b > a { (p - a) / (b - a) } else { p - a }
+        })
+    }
+
+    pub fn bounding_sphere(&self) -> (Point<T::Float, N>, T::Float) {
+        let center = self.p_min.lerp(&self.p_max, T::Float::HALF);
+
+        let is_empty = self.p_min.components.iter()
+            .zip(self.p_max.components.iter())
+            .any(|(&a, &b)| a.to_float() > b.to_float());
+
+        let p_max = Point::generate(|i| self.p_max.components[i].to_float());
+
+        let radius = match is_empty {
+            true => T::Float::ZERO,
+            false => center.distance(&p_max),
+        };
+
+        (center, radius)
+    }
+}
@@ -1,13 +1,14 @@
-use generic_array::typenum::U2;
+use std::ops::{Sub, Mul};
 use crate::geometry::point2::Point2;
+use crate::geometry::float::{Float, Real};
 use super::bounds::*;
 
-pub type Bounds2<T> = Bounds<T, U2>;
+pub type Bounds2<T> = Bounds<T, 2>;
 
-impl Default for Bounds2<f64> {
+impl<F: Float> Default for Bounds2<F> {
     fn default() -> Self {
-        let min = std::f64::MIN;
-        let max = std::f64::MAX;
+        let min = F::MIN;
+        let max = F::MAX;
 
         let p_min = Point2::new(max, max);
         let p_max = Point2::new(min, min);
@@ -18,8 +19,8 @@ impl Default for Bounds2<f64> {
 
 impl Default for Bounds2<i32> {
     fn default() -> Self {
-        let min = std::i32::MIN;
-        let max = std::i32::MAX;
+        let min = i32::MIN;
+        let max = i32::MAX;
 
         let p_min = Point2::new(max, max);
         let p_max = Point2::new(min, min);
@@ -28,7 +29,15 @@ impl Default for Bounds2<i32> {
     }
 }
 
-pub type Bounds2f = Bounds2<f64>;
+impl<T: PartialOrd + Sub<Output=T> + Mul<Output=T> + Default + Copy> Bounds2<T> {
+    pub fn area(&self) -> T {
+        let d = self.diagonal();
+
+        d.x() * d.y()
+    }
+}
+
+pub type Bounds2f = Bounds2<Real>;
 pub type Bounds2i = Bounds2<i32>;
 
 #[cfg(test)]
@@ -9,16 +9,16 @@ mod default {
     fn it_builds_an_empty_bounding_box_for_f64() {
         let subject = Subject::<f64>::default();
 
-        assert_eq!(subject.p_min.x(), std::f64::MAX);
-        assert_eq!(subject.p_max.y(), std::f64::MIN);
+        assert_eq!(subject.p_min.x(), f64::MAX);
+        assert_eq!(subject.p_max.y(), f64::MIN);
     }
 
     #[test]
     fn it_builds_an_empty_bounding_box_for_i32() {
         let subject = Subject::<i32>::default();
 
-        assert_eq!(subject.p_min.x(), std::i32::MAX);
-        assert_eq!(subject.p_max.y(), std::i32::MIN);
+        assert_eq!(subject.p_min.x(), i32::MAX);
+        assert_eq!(subject.p_max.y(), i32::MIN);
     }
 }
 
@@ -31,7 +31,7 @@ mod aliases {
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_point_of_double_precision_floats() {
+    fn it_has_a_type_alias_for_a_point_of_reals() {
         Bounds2f::default();
     }
 }
@@ -48,3 +48,58 @@ mod conversions {
         assert_eq!(&subject.p_max, point);
     }
 }
+
+mod union_point {
+    use super::*;
+
+    #[test]
+    fn it_grows_the_bounding_box_to_contain_the_point() {
+        let subject = Subject::new(&Point2::new(0, 0), &Point2::new(1, 1));
+        let subject = subject.union_point(&Point2::new(2, -1));
+
+        assert_eq!(subject.p_min, Point2::new(0, -1));
+        assert_eq!(subject.p_max, Point2::new(2, 1));
+    }
+}
+
+mod corner {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_four_corners_of_the_rectangle() {
+        let subject = Subject::new(&Point2::new(0, 0), &Point2::new(1, 2));
+
+        assert_eq!(subject.corner(0), Point2::new(0, 0));
+        assert_eq!(subject.corner(1), Point2::new(1, 0));
+        assert_eq!(subject.corner(2), Point2::new(0, 2));
+        assert_eq!(subject.corner(3), Point2::new(1, 2));
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_area_of_the_rectangle() {
+        let subject = Subject::new(&Point2::new(1, 1), &Point2::new(3, 4));
+
+        assert_eq!(subject.area(), 6);
+    }
+
+    #[test]
+    fn it_returns_zero_for_an_empty_rectangle() {
+        assert_eq!(Subject::<i32>::default().area(), 0);
+        assert_eq!(Subject::<f64>::default().area(), 0.0);
+    }
+}
+
+mod maximum_extent {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_index_of_the_longest_axis() {
+        let subject = Subject::new(&Point2::new(0.0, 0.0), &Point2::new(1.0, 2.0));
+
+        assert_eq!(subject.maximum_extent(), 1);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::geometry::point2::Point2i;
+use crate::geometry::bounds2::Bounds2i;
+use crate::geometry::bounds::Bounds;
+
+// Visits every integer point in the bounds in row-major order. The bounds are
+// half-open, i.e. points on the p_max boundary are not visited.
+pub struct Pixels {
+    bounds: Bounds2i,
+    next: Option<Point2i>,
+}
+
+impl Pixels {
+    pub fn new(bounds: &Bounds2i) -> Self {
+        let next = match is_empty(bounds) {
+            true => None,
+            false => Some(bounds.p_min),
+        };
+
+        Self { bounds: *bounds, next }
+    }
+}
+
+impl Iterator for Pixels {
+    type Item = Point2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.next.take()?;
+        let (p_min, p_max) = (&self.bounds.p_min, &self.bounds.p_max);
+
+        let (mut x, mut y) = (point.x() + 1, point.y());
+
+        if x >= p_max.x() {
+            x = p_min.x();
+            y += 1;
+        }
+
+        if y < p_max.y() {
+            self.next = Some(Point2i::new(x, y));
+        }
+
+        Some(point)
+    }
+}
+
+// Splits the bounds into square tiles of the given size, in row-major order.
+// Tiles on the right and bottom edges are clipped so they don't extend past p_max.
+pub struct Tiles {
+    bounds: Bounds2i,
+    tile_size: i32,
+    indexes: Pixels,
+}
+
+impl Tiles {
+    pub fn new(bounds: &Bounds2i, tile_size: i32) -> Self {
+        assert!(tile_size > 0, "tile size must be positive: {}", tile_size);
+
+        let counts = match is_empty(bounds) {
+            true => Point2i::new(0, 0),
+            false => {
+                let diagonal = bounds.diagonal();
+
+                // Rounds up without adding the tile size, which could overflow for large tiles.
+                let x = (diagonal.x() - 1) / tile_size + 1;
+                let y = (diagonal.y() - 1) / tile_size + 1;
+
+                Point2i::new(x, y)
+            },
+        };
+
+        let indexes = Pixels::new(&Bounds { p_min: Point2i::new(0, 0), p_max: counts });
+
+        Self { bounds: *bounds, tile_size, indexes }
+    }
+}
+
+impl Iterator for Tiles {
+    type Item = Bounds2i;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indexes.next()?;
+        let (p_min, p_max) = (&self.bounds.p_min, &self.bounds.p_max);
+
+        let x = p_min.x() + index.x() * self.tile_size;
+        let y = p_min.y() + index.y() * self.tile_size;
+
+        let tile_min = Point2i::new(x, y);
+        let tile_max = Point2i::new(
+            x.saturating_add(self.tile_size).min(p_max.x()),
+            y.saturating_add(self.tile_size).min(p_max.y()),
+        );
+
+        Some(Bounds { p_min: tile_min, p_max: tile_max })
+    }
+}
+
+fn is_empty(bounds: &Bounds2i) -> bool {
+    bounds.p_min.x() >= bounds.p_max.x() || bounds.p_min.y() >= bounds.p_max.y()
+}
+
+impl Bounds2i {
+    pub fn pixels(&self) -> Pixels {
+        Pixels::new(self)
+    }
+
+    pub fn tiles(&self, tile_size: i32) -> Tiles {
+        Tiles::new(self, tile_size)
+    }
+}
+
+impl IntoIterator for &Bounds2i {
+    type Item = Point2i;
+    type IntoIter = Pixels;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pixels()
+    }
+}
+
+#[cfg(test)]
+mod test;
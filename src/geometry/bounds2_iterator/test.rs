@@ -0,0 +1,117 @@
+use super::*;
+
+fn bounds(x0: i32, y0: i32, x1: i32, y1: i32) -> Bounds2i {
+    Bounds2i::new(&Point2i::new(x0, y0), &Point2i::new(x1, y1))
+}
+
+mod pixels {
+    use super::*;
+
+    #[test]
+    fn it_visits_every_point_in_row_major_order() {
+        let subject = bounds(1, 2, 3, 4).pixels();
+        let points = subject.collect::<Vec<_>>();
+
+        assert_eq!(points, vec![
+            Point2i::new(1, 2),
+            Point2i::new(2, 2),
+            Point2i::new(1, 3),
+            Point2i::new(2, 3),
+        ]);
+    }
+
+    #[test]
+    fn it_can_be_used_in_a_for_loop() {
+        let mut count = 0;
+
+        for _ in &bounds(0, 0, 4, 3) {
+            count += 1;
+        }
+
+        assert_eq!(count, 12);
+    }
+
+    #[test]
+    fn it_visits_nothing_if_the_bounds_have_zero_width_or_height() {
+        assert_eq!(bounds(0, 0, 0, 5).pixels().count(), 0);
+        assert_eq!(bounds(0, 0, 5, 0).pixels().count(), 0);
+    }
+
+    #[test]
+    fn it_visits_nothing_for_the_default_bounds() {
+        assert_eq!(Bounds2i::default().pixels().count(), 0);
+    }
+
+    #[test]
+    fn it_handles_negative_coordinates() {
+        let points = bounds(-1, -1, 0, 1).pixels().collect::<Vec<_>>();
+
+        assert_eq!(points, vec![Point2i::new(-1, -1), Point2i::new(-1, 0)]);
+    }
+}
+
+mod tiles {
+    use super::*;
+
+    #[test]
+    fn it_splits_the_bounds_into_tiles_in_row_major_order() {
+        let tiles = bounds(0, 0, 4, 4).tiles(2).collect::<Vec<_>>();
+
+        assert_eq!(tiles, vec![
+            bounds(0, 0, 2, 2),
+            bounds(2, 0, 4, 2),
+            bounds(0, 2, 2, 4),
+            bounds(2, 2, 4, 4),
+        ]);
+    }
+
+    #[test]
+    fn it_clips_the_tiles_to_the_parent_bounds() {
+        let tiles = bounds(1, 1, 6, 4).tiles(4).collect::<Vec<_>>();
+
+        assert_eq!(tiles, vec![
+            bounds(1, 1, 5, 4),
+            bounds(5, 1, 6, 4),
+        ]);
+    }
+
+    #[test]
+    fn it_covers_every_pixel_exactly_once() {
+        let parent = bounds(-3, 2, 10, 9);
+
+        let mut pixels = parent.tiles(3).flat_map(|t| t.pixels()).collect::<Vec<_>>();
+        let mut expected = parent.pixels().collect::<Vec<_>>();
+
+        pixels.sort_by_key(|p| (p.x(), p.y()));
+        expected.sort_by_key(|p| (p.x(), p.y()));
+
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn it_returns_no_tiles_for_empty_bounds() {
+        assert_eq!(bounds(0, 0, 0, 5).tiles(2).count(), 0);
+        assert_eq!(Bounds2i::default().tiles(2).count(), 0);
+    }
+
+    #[test]
+    fn it_returns_a_single_tile_if_the_tile_size_is_larger_than_the_bounds() {
+        let tiles = bounds(0, 0, 10, 10).tiles(i32::MAX).collect::<Vec<_>>();
+
+        assert_eq!(tiles, vec![bounds(0, 0, 10, 10)]);
+    }
+
+    #[test]
+    fn it_does_not_overflow_for_bounds_near_the_largest_coordinate() {
+        let tiles = bounds(i32::MAX - 10, 0, i32::MAX, 10).tiles(8).collect::<Vec<_>>();
+
+        assert_eq!(tiles.len(), 4);
+        assert_eq!(tiles[3], bounds(i32::MAX - 2, 8, i32::MAX, 10));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_if_the_tile_size_is_not_positive() {
+        bounds(0, 0, 4, 4).tiles(0);
+    }
+}
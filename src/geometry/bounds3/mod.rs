@@ -1,13 +1,16 @@
-use generic_array::typenum::U3;
+use std::ops::{Add, Sub, Mul};
 use crate::geometry::point3::Point3;
+use crate::geometry::vector3::Vector3f;
+use crate::geometry::ray::Ray;
+use crate::geometry::float::{Float, Real, gamma};
 use super::bounds::*;
 
-pub type Bounds3<T> = Bounds<T, U3>;
+pub type Bounds3<T> = Bounds<T, 3>;
 
-impl Default for Bounds3<f64> {
+impl<F: Float> Default for Bounds3<F> {
     fn default() -> Self {
-        let min = std::f64::MIN;
-        let max = std::f64::MAX;
+        let min = F::MIN;
+        let max = F::MAX;
 
         let p_min = Point3::new(max, max, max);
         let p_max = Point3::new(min, min, min);
@@ -18,8 +21,8 @@ impl Default for Bounds3<f64> {
 
 impl Default for Bounds3<i32> {
     fn default() -> Self {
-        let min = std::i32::MIN;
-        let max = std::i32::MAX;
+        let min = i32::MIN;
+        let max = i32::MAX;
 
         let p_min = Point3::new(max, max, max);
         let p_max = Point3::new(min, min, min);
@@ -28,7 +31,90 @@ impl Default for Bounds3<i32> {
     }
 }
 
-pub type Bounds3f = Bounds3<f64>;
+impl<T> Bounds3<T>
+    where T: PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Default + Copy,
+          T: From<u8>,
+{
+    pub fn surface_area(&self) -> T {
+        let d = self.diagonal();
+
+        T::from(2) * (d.x() * d.y() + d.x() * d.z() + d.y() * d.z())
+    }
+
+    pub fn volume(&self) -> T {
+        let d = self.diagonal();
+
+        d.x() * d.y() * d.z()
+    }
+}
+
+impl Bounds3<Real> {
+    // Returns the parametric range of the ray that's inside the box, clipped to t_max. The far
+    // value is pushed out by the floating point error so that rays grazing the box aren't missed.
+    pub fn intersect_p(&self, ray: &Ray) -> Option<(Real, Real)> {
+        let mut t0 = 0.0;
+        let mut t1 = ray.t_max;
+
+        for i in 0..3 {
+            let inverse_direction = ray.d.components[i].recip();
+            let origin = ray.o.components[i];
+
+            let mut t_near = (self.p_min.components[i] - origin) * inverse_direction;
+            let mut t_far = (self.p_max.components[i] - origin) * inverse_direction;
+
+            if t_near > t_far {
+                std::mem::swap(&mut t_near, &mut t_far);
+            }
+
+            t_far *= 1.0 + 2.0 * gamma(3);
+
+            // These comparisons are written so that NaNs (from 0 * infinity) are ignored.
+            t0 = if t_near > t0 { t_near } else { t0 };
+            t1 = if t_far < t1 { t_far } else { t1 };
+
+            if t0 > t1 {
+                return None;
+            }
+        }
+
+        Some((t0, t1))
+    }
+
+    // A faster version for when the same ray is tested against many boxes, e.g. in a BVH.
+    // The dir_is_neg array holds 1 for each axis where the ray's direction is negative.
+    pub fn intersect_p_fast(&self, ray: &Ray, inverse_direction: &Vector3f, dir_is_neg: &[usize; 3]) -> bool {
+        let error = 1.0 + 2.0 * gamma(3);
+
+        let slab = |axis: usize| {
+            let origin = ray.o.components[axis];
+            let inverse = inverse_direction.components[axis];
+
+            let near = (self[dir_is_neg[axis]].components[axis] - origin) * inverse;
+            let far = (self[1 - dir_is_neg[axis]].components[axis] - origin) * inverse;
+
+            (near, far * error)
+        };
+
+        let mut t_min = 0.0;
+        let mut t_max = ray.t_max;
+
+        for axis in 0..3 {
+            let (near, far) = slab(axis);
+
+            // As in intersect_p, NaNs from rays that lie in a slab's plane are ignored.
+            t_min = if near > t_min { near } else { t_min };
+            t_max = if far < t_max { far } else { t_max };
+
+            if t_min > t_max {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+pub type Bounds3f = Bounds3<Real>;
 pub type Bounds3i = Bounds3<i32>;
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
 use super::*;
+use crate::geometry::vector3::Vector3;
+use crate::geometry::point3::Point3f;
 
 type Subject<T> = Bounds3<T>;
 
@@ -9,16 +11,16 @@ mod default {
     fn it_builds_an_empty_bounding_box_for_f64() {
         let subject = Subject::<f64>::default();
 
-        assert_eq!(subject.p_min.x(), std::f64::MAX);
-        assert_eq!(subject.p_max.y(), std::f64::MIN);
+        assert_eq!(subject.p_min.x(), f64::MAX);
+        assert_eq!(subject.p_max.y(), f64::MIN);
     }
 
     #[test]
     fn it_builds_an_empty_bounding_box_for_i32() {
         let subject = Subject::<i32>::default();
 
-        assert_eq!(subject.p_min.y(), std::i32::MAX);
-        assert_eq!(subject.p_max.z(), std::i32::MIN);
+        assert_eq!(subject.p_min.y(), i32::MAX);
+        assert_eq!(subject.p_max.z(), i32::MIN);
     }
 }
 
@@ -31,7 +33,7 @@ mod aliases {
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_point_of_double_precision_floats() {
+    fn it_has_a_type_alias_for_a_point_of_reals() {
         Bounds3f::default();
     }
 }
@@ -48,3 +50,450 @@ mod conversions {
         assert_eq!(&subject.p_max, point);
     }
 }
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_bounding_box_from_the_component_wise_min_and_max_of_two_points() {
+        let a = Point3::new(1.0, 5.0, 3.0);
+        let b = Point3::new(4.0, 2.0, 6.0);
+
+        let subject = Subject::new(&a, &b);
+
+        assert_eq!(subject.p_min, Point3::new(1.0, 2.0, 3.0));
+        assert_eq!(subject.p_max, Point3::new(4.0, 5.0, 6.0));
+    }
+}
+
+mod union_point {
+    use super::*;
+
+    #[test]
+    fn it_grows_the_bounding_box_to_contain_the_point() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 1, 1));
+        let subject = subject.union_point(&Point3::new(2, -1, 0));
+
+        assert_eq!(subject.p_min, Point3::new(0, -1, 0));
+        assert_eq!(subject.p_max, Point3::new(2, 1, 1));
+    }
+
+    #[test]
+    fn it_builds_a_degenerate_box_when_starting_from_an_empty_box() {
+        let point = Point3::new(1.0, 2.0, 3.0);
+        let subject = Bounds3f::default().union_point(&point);
+
+        assert_eq!(subject.p_min, point);
+        assert_eq!(subject.p_max, point);
+    }
+}
+
+mod union {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_bounding_box_that_contains_both_boxes() {
+        let a = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 1, 1));
+        let b = Subject::new(&Point3::new(2, -1, 0), &Point3::new(3, 0, 4));
+
+        let subject = a.union(&b);
+
+        assert_eq!(subject.p_min, Point3::new(0, -1, 0));
+        assert_eq!(subject.p_max, Point3::new(3, 1, 4));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_region_where_the_boxes_overlap() {
+        let a = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+        let b = Subject::new(&Point3::new(1, -1, 1), &Point3::new(3, 1, 3));
+
+        let subject = a.intersect(&b);
+
+        assert_eq!(subject.p_min, Point3::new(1, 0, 1));
+        assert_eq!(subject.p_max, Point3::new(2, 1, 2));
+    }
+}
+
+mod overlaps {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_if_the_boxes_overlap() {
+        let a = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+        let b = Subject::new(&Point3::new(1, 1, 1), &Point3::new(3, 3, 3));
+
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+    }
+
+    #[test]
+    fn it_returns_true_if_the_boxes_touch() {
+        let a = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 1, 1));
+        let b = Subject::new(&Point3::new(1, 0, 0), &Point3::new(2, 1, 1));
+
+        assert!(a.overlaps(&b));
+    }
+
+    #[test]
+    fn it_returns_false_if_the_boxes_are_separated_on_any_axis() {
+        let a = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 1, 1));
+        let b = Subject::new(&Point3::new(0, 0, 2), &Point3::new(1, 1, 3));
+
+        assert!(!a.overlaps(&b));
+    }
+}
+
+mod inside {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_if_the_point_is_inside_or_on_the_boundary() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+
+        assert!(subject.inside(&Point3::new(1, 1, 1)));
+        assert!(subject.inside(&Point3::new(0, 2, 2)));
+    }
+
+    #[test]
+    fn it_returns_false_if_the_point_is_outside() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+
+        assert!(!subject.inside(&Point3::new(1, 3, 1)));
+    }
+}
+
+mod inside_exclusive {
+    use super::*;
+
+    #[test]
+    fn it_returns_false_if_the_point_is_on_the_upper_boundary() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+
+        assert!(subject.inside_exclusive(&Point3::new(0, 1, 1)));
+        assert!(!subject.inside_exclusive(&Point3::new(1, 2, 1)));
+    }
+}
+
+mod expand {
+    use super::*;
+
+    #[test]
+    fn it_pads_the_bounding_box_by_a_constant_in_every_direction() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 2, 2));
+        let subject = subject.expand(1);
+
+        assert_eq!(subject.p_min, Point3::new(-1, -1, -1));
+        assert_eq!(subject.p_max, Point3::new(3, 3, 3));
+    }
+}
+
+mod diagonal {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_vector_from_the_min_corner_to_the_max_corner() {
+        let subject = Subject::new(&Point3::new(1, 2, 3), &Point3::new(2, 4, 6));
+
+        assert_eq!(subject.diagonal(), Vector3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn it_returns_zero_for_the_axes_where_the_box_is_empty() {
+        assert_eq!(Subject::<i32>::default().diagonal(), Vector3::new(0, 0, 0));
+        assert_eq!(Subject::<f64>::default().diagonal(), Vector3::new(0.0, 0.0, 0.0));
+    }
+}
+
+mod corner {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_corners_of_the_box_using_the_bits_of_the_index() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 2, 3));
+
+        assert_eq!(subject.corner(0), Point3::new(0, 0, 0));
+        assert_eq!(subject.corner(1), Point3::new(1, 0, 0));
+        assert_eq!(subject.corner(2), Point3::new(0, 2, 0));
+        assert_eq!(subject.corner(5), Point3::new(1, 0, 3));
+        assert_eq!(subject.corner(7), Point3::new(1, 2, 3));
+    }
+}
+
+mod surface_area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_total_area_of_the_six_faces() {
+        let subject = Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(subject.surface_area(), 22.0);
+    }
+
+    #[test]
+    fn it_returns_zero_for_an_empty_box() {
+        assert_eq!(Subject::<i32>::default().surface_area(), 0);
+        assert_eq!(Subject::<f64>::default().surface_area(), 0.0);
+    }
+}
+
+mod volume {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_volume_of_the_box() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(1, 2, 3));
+
+        assert_eq!(subject.volume(), 6);
+    }
+
+    #[test]
+    fn it_returns_zero_for_an_empty_box() {
+        assert_eq!(Subject::<i32>::default().volume(), 0);
+    }
+}
+
+mod maximum_extent {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_index_of_the_longest_axis() {
+        let x = Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(3.0, 2.0, 1.0));
+        let y = Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 3.0, 2.0));
+        let z = Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 2.0, 3.0));
+
+        assert_eq!(x.maximum_extent(), 0);
+        assert_eq!(y.maximum_extent(), 1);
+        assert_eq!(z.maximum_extent(), 2);
+    }
+}
+
+mod lerp {
+    use super::*;
+
+    #[test]
+    fn it_linearly_interpolates_between_the_corners_on_each_axis() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 4, 8));
+        let point = subject.lerp(&Point3f::new(0.5, 0.25, 1.0));
+
+        assert_eq!(point, Point3f::new(1.0, 1.0, 8.0));
+    }
+}
+
+mod offset {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_position_of_the_point_relative_to_the_corners() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 4, 8));
+        let offset = subject.offset(&Point3::new(1, 1, 8));
+
+        assert_eq!(offset, Vector3f::new(0.5, 0.25, 1.0));
+    }
+
+    #[test]
+    fn it_does_not_divide_by_zero_for_degenerate_axes() {
+        let subject = Subject::new(&Point3::new(0, 0, 0), &Point3::new(2, 0, 8));
+        let offset = subject.offset(&Point3::new(1, 0, 8));
+
+        assert_eq!(offset, Vector3f::new(0.5, 0.0, 1.0));
+    }
+}
+
+mod bounding_sphere {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_sphere_that_contains_the_box() {
+        let subject = Bounds3f::new(&Point3f::new(0.0, 0.0, 0.0), &Point3f::new(2.0, 2.0, 2.0));
+        let (center, radius) = subject.bounding_sphere();
+
+        assert_eq!(center, Point3f::new(1.0, 1.0, 1.0));
+        assert_eq!(radius, (3.0 as Real).sqrt());
+    }
+
+    #[test]
+    fn it_returns_a_radius_of_zero_for_an_empty_box() {
+        let subject = Bounds3i::default();
+        let (_, radius) = subject.bounding_sphere();
+
+        assert_eq!(radius, 0.0);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    fn unit_box() -> Bounds3f {
+        Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 1.0, 1.0))
+    }
+
+    fn ray(origin: (Real, Real, Real), direction: (Real, Real, Real), t_max: Option<Real>) -> Ray {
+        let o = Point3::new(origin.0, origin.1, origin.2);
+        let d = Vector3f::new(direction.0, direction.1, direction.2);
+
+        Ray::new(o, d, t_max, None, None)
+    }
+
+    #[test]
+    fn it_returns_the_parametric_range_where_the_ray_is_inside_the_box() {
+        let ray = ray((-1.0, 0.5, 0.5), (1.0, 0.0, 0.0), None);
+        let (t0, t1) = unit_box().intersect_p(&ray).unwrap();
+
+        assert_eq!(t0, 1.0);
+        assert!((2.0..=2.0 * (1.0 + 2.0 * gamma(3))).contains(&t1));
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses_the_box() {
+        let ray = ray((-1.0, 2.0, 0.5), (1.0, 0.0, 0.0), None);
+
+        assert_eq!(unit_box().intersect_p(&ray), None);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_box_is_behind_the_ray() {
+        let ray = ray((2.0, 0.5, 0.5), (1.0, 0.0, 0.0), None);
+
+        assert_eq!(unit_box().intersect_p(&ray), None);
+    }
+
+    #[test]
+    fn it_clips_the_range_to_t_max() {
+        let ray = ray((-1.0, 0.5, 0.5), (1.0, 0.0, 0.0), Some(1.5));
+        let (t0, t1) = unit_box().intersect_p(&ray).unwrap();
+
+        assert_eq!(t0, 1.0);
+        assert_eq!(t1, 1.5);
+    }
+
+    #[test]
+    fn it_returns_none_if_t_max_is_before_the_box() {
+        let ray = ray((-1.0, 0.5, 0.5), (1.0, 0.0, 0.0), Some(0.5));
+
+        assert_eq!(unit_box().intersect_p(&ray), None);
+    }
+
+    #[test]
+    fn it_starts_the_range_at_zero_if_the_ray_starts_inside_the_box() {
+        let ray = ray((0.5, 0.5, 0.5), (0.0, 0.0, -1.0), None);
+        let (t0, _) = unit_box().intersect_p(&ray).unwrap();
+
+        assert_eq!(t0, 0.0);
+    }
+
+    #[test]
+    fn it_does_not_miss_rays_that_graze_an_edge_of_the_box() {
+        let ray = ray((-1.0, 1.0, 1.0), (1.0, 0.0, 0.0), None);
+
+        assert!(unit_box().intersect_p(&ray).is_some());
+    }
+
+    #[test]
+    fn it_handles_rays_that_travel_along_a_face_of_the_box() {
+        let ray = ray((0.0, -1.0, 0.5), (0.0, 1.0, 0.0), None);
+
+        assert!(unit_box().intersect_p(&ray).is_some());
+    }
+}
+
+mod intersect_p_fast {
+    use super::*;
+
+    fn unit_box() -> Bounds3f {
+        Subject::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 1.0, 1.0))
+    }
+
+    fn intersects(bounds: &Bounds3f, ray: &Ray) -> bool {
+        let inverse_direction = Vector3f::new(1.0 / ray.d.x(), 1.0 / ray.d.y(), 1.0 / ray.d.z());
+        let dir_is_neg = [
+            (inverse_direction.x() < 0.0) as usize,
+            (inverse_direction.y() < 0.0) as usize,
+            (inverse_direction.z() < 0.0) as usize,
+        ];
+
+        bounds.intersect_p_fast(ray, &inverse_direction, &dir_is_neg)
+    }
+
+    #[test]
+    fn it_returns_true_if_the_ray_hits_the_box() {
+        let ray = Ray::new(Point3::new(2.0, 2.0, 2.0), Vector3f::new(-1.0, -1.0, -1.0), None, None, None);
+
+        assert!(intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_returns_false_if_the_ray_misses_the_box() {
+        let ray = Ray::new(Point3::new(2.0, 2.0, 2.0), Vector3f::new(-1.0, 1.0, -1.0), None, None, None);
+
+        assert!(!intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_returns_false_if_t_max_is_before_the_box() {
+        let ray = Ray::new(Point3::new(2.0, 2.0, 2.0), Vector3f::new(-1.0, -1.0, -1.0), Some(0.5), None, None);
+
+        assert!(!intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_handles_zero_direction_components() {
+        let ray = Ray::new(Point3::new(0.5, 0.5, -1.0), Vector3f::new(0.0, 0.0, 1.0), None, None, None);
+
+        assert!(intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_does_not_miss_rays_that_graze_an_edge_of_the_box() {
+        let ray = Ray::new(Point3::new(-1.0, 1.0, 1.0), Vector3f::new(1.0, 0.0, 0.0), None, None, None);
+
+        assert!(intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_handles_rays_that_travel_along_a_face_of_the_box() {
+        let ray = Ray::new(Point3::new(0.0, -1.0, 0.5), Vector3f::new(0.0, 1.0, 0.0), None, None, None);
+
+        assert!(intersects(&unit_box(), &ray));
+    }
+
+    #[test]
+    fn it_agrees_with_intersect_p_for_rays_in_a_face_plane() {
+        let ray = Ray::new(Point3::new(0.0, 0.5, -1.0), Vector3f::new(0.0, 0.0, 1.0), None, None, None);
+
+        assert!(unit_box().intersect_p(&ray).is_some());
+        assert!(intersects(&unit_box(), &ray));
+    }
+}
+
+mod approx_eq {
+    use super::*;
+    use crate::assert_approx;
+    use crate::geometry::approx_eq::ApproxEq;
+
+    #[test]
+    fn it_compares_both_corners() {
+        let subject = Bounds3f::new(&Point3::new(0.0, 0.0, 0.0), &Point3::new(1.0, 1.0, 1.0));
+        let other = Bounds3f::new(&Point3::new(0.0, 0.0, 1e-9), &Point3::new(1.0, 1.0, 1.0 - 1e-9));
+
+        assert_approx!(subject, other, epsilon = 1e-6);
+        assert!(!subject.abs_diff_eq(&other, 1e-12));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_serializes_the_corners_as_a_pair_of_arrays() {
+        let subject = Bounds3i::new(&Point3::new(0, 0, 0), &Point3::new(1, 2, 3));
+        let json = serde_json::to_string(&subject).unwrap();
+
+        assert_eq!(json, "[[0,0,0],[1,2,3]]");
+        assert_eq!(serde_json::from_str::<Bounds3i>(&json).unwrap(), subject);
+    }
+}
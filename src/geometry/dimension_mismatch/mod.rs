@@ -0,0 +1,36 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::error::Error;
+
+// Returned when building a point, vector or normal from a number of components that
+// doesn't match its dimension, e.g. a slice of two values for a Point3.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DimensionMismatch {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {} components but got {}", self.expected, self.actual)
+    }
+}
+
+impl Error for DimensionMismatch { }
+
+// These convert exactly N components to an array or return an error with the number given.
+pub fn components_from_vec<T, const N: usize>(components: Vec<T>) -> Result<[T; N], DimensionMismatch> {
+    let actual = components.len();
+
+    <[T; N]>::try_from(components).map_err(|_| DimensionMismatch { expected: N, actual })
+}
+
+pub fn components_from_slice<T: Clone, const N: usize>(components: &[T]) -> Result<[T; N], DimensionMismatch> {
+    let actual = components.len();
+
+    <&[T; N]>::try_from(components).cloned()
+        .map_err(|_| DimensionMismatch { expected: N, actual })
+}
+
+#[cfg(test)]
+mod test;
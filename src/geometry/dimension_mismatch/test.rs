@@ -0,0 +1,57 @@
+use super::*;
+
+type Subject = DimensionMismatch;
+
+mod components_from_vec {
+    use super::*;
+
+    #[test]
+    fn it_converts_the_components_if_there_are_the_right_number() {
+        let components: [i32; 3] = components_from_vec(vec![1, 2, 3]).unwrap();
+
+        assert_eq!(components, [1, 2, 3]);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_there_are_too_few_components() {
+        let result: Result<[i32; 3], _> = components_from_vec(vec![1, 2]);
+
+        assert_eq!(result, Err(Subject { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn it_returns_an_error_if_there_are_too_many_components() {
+        let result: Result<[i32; 3], _> = components_from_vec(vec![1, 2, 3, 4]);
+
+        assert_eq!(result, Err(Subject { expected: 3, actual: 4 }));
+    }
+}
+
+mod components_from_slice {
+    use super::*;
+
+    #[test]
+    fn it_converts_the_components_if_there_are_the_right_number() {
+        let components: [i32; 2] = components_from_slice(&[1, 2]).unwrap();
+
+        assert_eq!(components, [1, 2]);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result: Result<[i32; 2], _> = components_from_slice(&[1, 2, 3]);
+
+        assert_eq!(result, Err(Subject { expected: 2, actual: 3 }));
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn it_describes_the_expected_and_actual_dimensions() {
+        let subject = Subject { expected: 3, actual: 2 };
+
+        assert_eq!(subject.to_string(), "expected 3 components but got 2");
+    }
+}
@@ -0,0 +1,256 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+use super::float::{Float, Real, next_float_up, next_float_down, MACHINE_EPSILON};
+
+// The shadow value is only tracked in debug builds. In release builds it's a zero-sized
+// type whose operations do nothing so that EFloat costs no more than its bounds.
+#[cfg(debug_assertions)]
+mod precise;
+
+#[cfg(not(debug_assertions))]
+mod untracked;
+
+#[cfg(debug_assertions)]
+use precise::Precise;
+
+#[cfg(not(debug_assertions))]
+use untracked::Precise;
+
+// A float that keeps track of an interval that the exact result is guaranteed to be in.
+// Each operation rounds the interval outwards so the bounds remain conservative. In debug
+// builds, a higher precision shadow value is carried along to check that this is true.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EFloat {
+    v: Real,
+    low: Real,
+    high: Real,
+    precise: Precise,
+}
+
+impl EFloat {
+    pub fn new(v: Real, error: Real) -> Self {
+        let (low, high) = if error == 0.0 {
+            (v, v)
+        } else {
+            (next_float_down(v - error), next_float_up(v + error))
+        };
+
+        Self::from_parts(v, low, high, Precise::from(v.to_f64()))
+    }
+
+    fn from_parts(v: Real, low: Real, high: Real, precise: Precise) -> Self {
+        let efloat = Self { v, low, high, precise };
+        efloat.check();
+
+        efloat
+    }
+
+    pub fn value(&self) -> Real {
+        self.v
+    }
+
+    pub fn lower_bound(&self) -> Real {
+        self.low
+    }
+
+    pub fn upper_bound(&self) -> Real {
+        self.high
+    }
+
+    pub fn absolute_error(&self) -> Real {
+        next_float_up((self.high - self.v).abs().max((self.v - self.low).abs()))
+    }
+
+    #[cfg(debug_assertions)]
+    pub fn precise_value(&self) -> f64 {
+        self.precise.into()
+    }
+
+    #[cfg(debug_assertions)]
+    pub fn relative_error(&self) -> f64 {
+        let precise = self.precise_value();
+
+        ((precise - self.v.to_f64()) / precise).abs()
+    }
+
+    pub fn sqrt(&self) -> Self {
+        // Parts of the interval below zero are outside the domain of sqrt so they're ignored.
+        let low = next_float_down(self.low.max(0.0).sqrt());
+        let high = next_float_up(self.high.sqrt());
+
+        Self::from_parts(self.v.sqrt(), low, high, self.precise.sqrt())
+    }
+
+    pub fn abs(&self) -> Self {
+        let (low, high) = if self.low >= 0.0 {
+            (self.low, self.high)
+        } else if self.high <= 0.0 {
+            (-self.high, -self.low)
+        } else {
+            (0.0, self.high.max(-self.low))
+        };
+
+        Self::from_parts(self.v.abs(), low, high, self.precise.abs())
+    }
+
+    #[cfg(debug_assertions)]
+    fn check(&self) {
+        let finite = |v: Real| v.is_finite();
+
+        if finite(self.low) && finite(self.high) {
+            debug_assert!(self.low <= self.high, "{:?} has a lower bound above its upper bound", self);
+        }
+
+        if finite(self.v) && self.precise.is_finite() {
+            debug_assert!(self.precise.at_least(self.low.to_f64()), "{:?} has a lower bound above its precise value", self);
+            debug_assert!(self.precise.at_most(self.high.to_f64()), "{:?} has an upper bound below its precise value", self);
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn check(&self) { }
+}
+
+impl From<Real> for EFloat {
+    fn from(v: Real) -> Self {
+        Self::new(v, 0.0)
+    }
+}
+
+impl From<EFloat> for Real {
+    fn from(efloat: EFloat) -> Self {
+        efloat.v
+    }
+}
+
+impl Add for EFloat {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let low = next_float_down(self.low + other.low);
+        let high = next_float_up(self.high + other.high);
+
+        Self::from_parts(self.v + other.v, low, high, self.precise + other.precise)
+    }
+}
+
+impl Sub for EFloat {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        let low = next_float_down(self.low - other.high);
+        let high = next_float_up(self.high - other.low);
+
+        Self::from_parts(self.v - other.v, low, high, self.precise - other.precise)
+    }
+}
+
+impl Mul for EFloat {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let products = [
+            self.low * other.low,
+            self.high * other.low,
+            self.low * other.high,
+            self.high * other.high,
+        ];
+
+        let low = next_float_down(products.iter().cloned().fold(Real::INFINITY, Real::min));
+        let high = next_float_up(products.iter().cloned().fold(Real::NEG_INFINITY, Real::max));
+
+        Self::from_parts(self.v * other.v, low, high, self.precise * other.precise)
+    }
+}
+
+impl Div for EFloat {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        // The quotient is unbounded if the divisor's interval contains zero.
+        let (low, high) = if other.low < 0.0 && other.high > 0.0 {
+            (Real::NEG_INFINITY, Real::INFINITY)
+        } else {
+            let quotients = [
+                self.low / other.low,
+                self.high / other.low,
+                self.low / other.high,
+                self.high / other.high,
+            ];
+
+            let low = quotients.iter().cloned().fold(Real::INFINITY, Real::min);
+            let high = quotients.iter().cloned().fold(Real::NEG_INFINITY, Real::max);
+
+            (next_float_down(low), next_float_up(high))
+        };
+
+        Self::from_parts(self.v / other.v, low, high, self.precise / other.precise)
+    }
+}
+
+impl Neg for EFloat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(-self.v, -self.high, -self.low, -self.precise)
+    }
+}
+
+impl Add<Real> for EFloat {
+    type Output = Self;
+
+    fn add(self, other: Real) -> Self::Output {
+        self + EFloat::from(other)
+    }
+}
+
+impl Sub<Real> for EFloat {
+    type Output = Self;
+
+    fn sub(self, other: Real) -> Self::Output {
+        self - EFloat::from(other)
+    }
+}
+
+impl Mul<Real> for EFloat {
+    type Output = Self;
+
+    fn mul(self, other: Real) -> Self::Output {
+        self * EFloat::from(other)
+    }
+}
+
+impl Div<Real> for EFloat {
+    type Output = Self;
+
+    fn div(self, other: Real) -> Self::Output {
+        self / EFloat::from(other)
+    }
+}
+
+// Solves a*t^2 + b*t + c = 0 and returns the roots in ascending order of their lower bounds.
+// Returns None if there are no real roots.
+pub fn quadratic(a: EFloat, b: EFloat, c: EFloat) -> Option<(EFloat, EFloat)> {
+    let discriminant = b * b - a * c * 4.0;
+
+    if discriminant.value() < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let root = root + EFloat::new(0.0, MACHINE_EPSILON * root.value());
+
+    // Avoids catastrophic cancellation by never subtracting values of similar magnitude.
+    let q = if b.value() < 0.0 { (b - root) * -0.5 } else { (b + root) * -0.5 };
+
+    let t0 = q / a;
+    let t1 = c / q;
+
+    if t0.lower_bound() > t1.lower_bound() {
+        Some((t1, t0))
+    } else {
+        Some((t0, t1))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,122 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+// A double-double number, i.e. an unevaluated sum of two floats that has roughly twice the
+// precision of f64. It's only used to check the bounds of EFloat in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precise {
+    hi: f64,
+    lo: f64,
+}
+
+impl Precise {
+    fn new(hi: f64, lo: f64) -> Self {
+        // Renormalize so that lo is small relative to hi.
+        let sum = hi + lo;
+        let lo = lo - (sum - hi);
+
+        Self { hi: sum, lo }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.hi.is_finite() && self.lo.is_finite()
+    }
+
+    pub fn at_least(&self, v: f64) -> bool {
+        self.hi > v || self.hi == v && self.lo >= 0.0
+    }
+
+    pub fn at_most(&self, v: f64) -> bool {
+        self.hi < v || self.hi == v && self.lo <= 0.0
+    }
+
+    pub fn sqrt(&self) -> Self {
+        if self.hi <= 0.0 {
+            return Self::from(0.0);
+        }
+
+        let s = self.hi.sqrt();
+        let remainder = *self - Self::from(s) * Self::from(s);
+
+        Self::new(s, remainder.hi / (2.0 * s))
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.hi < 0.0 { -*self } else { *self }
+    }
+}
+
+// The exact sum of two floats as a float and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let error = (a - (sum - b_virtual)) + (b - b_virtual);
+
+    (sum, error)
+}
+
+// The exact product of two floats as a float and its rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+
+    (product, a.mul_add(b, -product))
+}
+
+impl From<f64> for Precise {
+    fn from(v: f64) -> Self {
+        Self { hi: v, lo: 0.0 }
+    }
+}
+
+impl From<Precise> for f64 {
+    fn from(precise: Precise) -> Self {
+        precise.hi + precise.lo
+    }
+}
+
+impl Add for Precise {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let (sum, error) = two_sum(self.hi, other.hi);
+
+        Self::new(sum, error + self.lo + other.lo)
+    }
+}
+
+impl Sub for Precise {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for Precise {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let (product, error) = two_product(self.hi, other.hi);
+
+        Self::new(product, error + self.hi * other.lo + self.lo * other.hi)
+    }
+}
+
+impl Div for Precise {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        let q1 = self.hi / other.hi;
+        let remainder = self - other * Self::from(q1);
+        let q2 = remainder.hi / other.hi;
+
+        Self::new(q1, q2)
+    }
+}
+
+impl Neg for Precise {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { hi: -self.hi, lo: -self.lo }
+    }
+}
@@ -0,0 +1,255 @@
+use super::*;
+
+type Subject = EFloat;
+
+fn contains(efloat: &EFloat, v: Real) -> bool {
+    efloat.lower_bound() <= v && v <= efloat.upper_bound()
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_an_efloat_with_bounds_that_contain_the_error() {
+        let subject = Subject::new(1.0, 0.5);
+
+        assert_eq!(subject.value(), 1.0);
+        assert!(subject.lower_bound() <= 0.5);
+        assert!(subject.upper_bound() >= 1.5);
+    }
+
+    #[test]
+    fn it_has_exact_bounds_if_there_is_no_error() {
+        let subject = Subject::new(1.0, 0.0);
+
+        assert_eq!(subject.lower_bound(), 1.0);
+        assert_eq!(subject.upper_bound(), 1.0);
+    }
+}
+
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn it_can_build_an_exact_efloat_from_a_float() {
+        let subject: Subject = 2.5.into();
+
+        assert_eq!(subject, Subject::new(2.5, 0.0));
+    }
+
+    #[test]
+    fn it_can_convert_an_efloat_to_its_value() {
+        let subject = Subject::new(2.5, 0.1);
+        let value: Real = subject.into();
+
+        assert_eq!(value, 2.5);
+    }
+}
+
+mod absolute_error {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_largest_distance_from_the_value_to_a_bound() {
+        let subject = Subject::new(1.0, 0.25);
+
+        assert!(subject.absolute_error() >= 0.25);
+        assert!(subject.absolute_error() < 0.26);
+    }
+}
+
+mod arithmetic {
+    use super::*;
+
+    #[test]
+    fn it_widens_the_bounds_when_adding() {
+        let subject = Subject::new(1.0, 0.1) + Subject::new(2.0, 0.2);
+
+        assert_eq!(subject.value(), 3.0);
+        assert!(contains(&subject, 2.7));
+        assert!(contains(&subject, 3.3));
+    }
+
+    #[test]
+    fn it_widens_the_bounds_when_subtracting() {
+        let subject = Subject::new(1.0, 0.1) - Subject::new(2.0, 0.2);
+
+        assert_eq!(subject.value(), -1.0);
+        assert!(contains(&subject, -1.3));
+        assert!(contains(&subject, -0.7));
+    }
+
+    #[test]
+    fn it_uses_the_extremes_of_the_products_when_multiplying() {
+        let subject = Subject::new(-1.0, 2.0) * Subject::new(3.0, 1.0);
+
+        assert_eq!(subject.value(), -3.0);
+        assert!(contains(&subject, -12.0));
+        assert!(contains(&subject, 4.0));
+    }
+
+    #[test]
+    fn it_uses_the_extremes_of_the_quotients_when_dividing() {
+        let subject = Subject::new(1.0, 0.5) / Subject::new(2.0, 1.0);
+
+        assert_eq!(subject.value(), 0.5);
+        assert!(contains(&subject, 0.5 / 3.0));
+        assert!(contains(&subject, 1.5));
+    }
+
+    #[test]
+    fn it_returns_infinite_bounds_if_the_divisor_might_be_zero() {
+        let subject = Subject::new(1.0, 0.0) / Subject::new(0.5, 1.0);
+
+        assert_eq!(subject.lower_bound(), Real::NEG_INFINITY);
+        assert_eq!(subject.upper_bound(), Real::INFINITY);
+    }
+
+    #[test]
+    fn it_negates_the_value_and_swaps_the_bounds() {
+        let subject = -Subject::new(1.0, 0.5);
+
+        assert_eq!(subject.value(), -1.0);
+        assert!(contains(&subject, -1.5));
+        assert!(contains(&subject, -0.5));
+    }
+
+    #[test]
+    fn it_supports_arithmetic_with_plain_floats() {
+        let subject = (Subject::from(1.0) + 2.0 - 0.5) * 4.0 / 2.0;
+
+        assert_eq!(subject.value(), 5.0);
+        assert!(contains(&subject, 5.0));
+    }
+
+    #[test]
+    fn it_accounts_for_rounding_errors() {
+        let subject = Subject::from(0.1) + Subject::from(0.2);
+
+        assert!(subject.lower_bound() < subject.value());
+        assert!(subject.upper_bound() > subject.value());
+    }
+}
+
+mod sqrt {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_square_root_with_bounds() {
+        let subject = Subject::new(4.0, 0.0).sqrt();
+
+        assert_eq!(subject.value(), 2.0);
+        assert!(contains(&subject, 2.0));
+    }
+
+    #[test]
+    fn it_clamps_the_lower_bound_at_zero_if_the_interval_is_partly_negative() {
+        let subject = Subject::new(0.5, 1.0).sqrt();
+
+        assert_eq!(subject.lower_bound(), next_float_down(0.0));
+        assert!(contains(&subject, (1.5 as Real).sqrt()));
+    }
+}
+
+mod abs {
+    use super::*;
+
+    #[test]
+    fn it_keeps_the_bounds_if_they_are_positive() {
+        let subject = Subject::new(2.0, 1.0).abs();
+
+        assert!(contains(&subject, 1.0));
+        assert!(contains(&subject, 3.0));
+    }
+
+    #[test]
+    fn it_negates_and_swaps_the_bounds_if_they_are_negative() {
+        let subject = Subject::new(-2.0, 1.0).abs();
+
+        assert_eq!(subject.value(), 2.0);
+        assert!(contains(&subject, 1.0));
+        assert!(contains(&subject, 3.0));
+    }
+
+    #[test]
+    fn it_has_a_lower_bound_of_zero_if_the_interval_contains_zero() {
+        let subject = Subject::new(-1.0, 3.0).abs();
+
+        assert_eq!(subject.value(), 1.0);
+        assert_eq!(subject.lower_bound(), 0.0);
+        assert!(contains(&subject, 4.0));
+    }
+}
+
+#[cfg(debug_assertions)]
+mod precise {
+    use super::*;
+
+    #[test]
+    fn it_tracks_a_higher_precision_value_that_is_within_the_bounds() {
+        let subject = (Subject::from(0.1) + Subject::from(0.2)) * Subject::from(3.0) / Subject::from(7.0);
+
+        let precise = subject.precise_value();
+
+        assert!(subject.lower_bound().to_f64() <= precise && precise <= subject.upper_bound().to_f64());
+        assert!(subject.relative_error() < 4.0 * Real::EPSILON.to_f64());
+    }
+
+    #[test]
+    fn it_keeps_the_precise_value_through_square_roots() {
+        let subject = Subject::from(2.0).sqrt();
+        let squared = subject * subject;
+
+        assert!((squared.precise_value() - 2.0).abs() < 1e-30);
+    }
+}
+
+mod quadratic {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_roots_in_ascending_order() {
+        // (t - 2)(t - 3) = t^2 - 5t + 6
+        let (t0, t1) = quadratic(1.0.into(), (-5.0).into(), 6.0.into()).unwrap();
+
+        assert!(contains(&t0, 2.0));
+        assert!(contains(&t1, 3.0));
+        assert!(t0.value() < t1.value());
+    }
+
+    #[test]
+    fn it_returns_none_if_there_are_no_real_roots() {
+        assert_eq!(quadratic(1.0.into(), 0.0.into(), 1.0.into()), None);
+    }
+
+    #[test]
+    fn it_returns_a_repeated_root_twice() {
+        // (t + 1)^2 = t^2 + 2t + 1
+        let (t0, t1) = quadratic(1.0.into(), 2.0.into(), 1.0.into()).unwrap();
+
+        assert!(contains(&t0, -1.0));
+        assert!(contains(&t1, -1.0));
+    }
+
+    #[test]
+    fn it_bounds_roots_that_suffer_from_cancellation() {
+        // The small root of t^2 - 1e8 t + 1 is about 1e-8, which the textbook formula gets wrong.
+        let (t0, t1) = quadratic(1.0.into(), (-1e8).into(), 1.0.into()).unwrap();
+
+        assert!(contains(&t0, 1e-8));
+        assert!(contains(&t1, 1e8 - 1e-8));
+        assert!(t0.absolute_error() < 1e-8 * 16.0 * MACHINE_EPSILON);
+    }
+
+    #[test]
+    fn it_propagates_the_error_of_the_coefficients() {
+        let a = Subject::new(1.0, 0.01);
+        let b = Subject::new(0.0, 0.0);
+        let c = Subject::new(-4.0, 0.0);
+
+        let (t0, t1) = quadratic(a, b, c).unwrap();
+
+        assert!(contains(&t0, -(4.0 as Real / 1.01).sqrt()));
+        assert!(contains(&t1, (4.0 as Real / 0.99).sqrt()));
+    }
+}
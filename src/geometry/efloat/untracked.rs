@@ -0,0 +1,60 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precise;
+
+impl Precise {
+    pub fn sqrt(&self) -> Self {
+        Precise
+    }
+
+    pub fn abs(&self) -> Self {
+        Precise
+    }
+}
+
+impl From<f64> for Precise {
+    fn from(_: f64) -> Self {
+        Precise
+    }
+}
+
+impl Add for Precise {
+    type Output = Self;
+
+    fn add(self, _: Self) -> Self::Output {
+        Precise
+    }
+}
+
+impl Sub for Precise {
+    type Output = Self;
+
+    fn sub(self, _: Self) -> Self::Output {
+        Precise
+    }
+}
+
+impl Mul for Precise {
+    type Output = Self;
+
+    fn mul(self, _: Self) -> Self::Output {
+        Precise
+    }
+}
+
+impl Div for Precise {
+    type Output = Self;
+
+    fn div(self, _: Self) -> Self::Output {
+        Precise
+    }
+}
+
+impl Neg for Precise {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Precise
+    }
+}
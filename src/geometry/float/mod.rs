@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+
+// The precision used for the ...f aliases and for types that aren't generic, such as
+// Transform and Ray. It's f64 unless the crate is built with the f32 feature.
+#[cfg(not(feature = "f32"))]
+pub type Real = f64;
+
+#[cfg(feature = "f32")]
+pub type Real = f32;
+
+// The operations that geometry needs from a floating point type.
+pub trait Float: Scalar<Float=Self> + Debug + Default + PartialOrd + Sum
+    + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const HALF: Self;
+    const PI: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const MAX: Self;
+    const MIN: Self;
+
+    fn from_f64(v: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn recip(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+
+    fn next_float_up(self) -> Self;
+    fn next_float_down(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const HALF: Self = 0.5;
+            const PI: Self = std::$t::consts::PI;
+            const EPSILON: Self = $t::EPSILON;
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const MAX: Self = $t::MAX;
+            const MIN: Self = $t::MIN;
+
+            fn from_f64(v: f64) -> Self { v as $t }
+            fn to_f64(self) -> f64 { self.into() }
+
+            fn abs(self) -> Self { $t::abs(self) }
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+            fn recip(self) -> Self { $t::recip(self) }
+            fn floor(self) -> Self { $t::floor(self) }
+            fn ceil(self) -> Self { $t::ceil(self) }
+            fn min(self, other: Self) -> Self { $t::min(self, other) }
+            fn max(self, other: Self) -> Self { $t::max(self, other) }
+            fn clamp(self, min: Self, max: Self) -> Self { $t::clamp(self, min, max) }
+            fn sin(self) -> Self { $t::sin(self) }
+            fn cos(self) -> Self { $t::cos(self) }
+            fn acos(self) -> Self { $t::acos(self) }
+            fn atan2(self, other: Self) -> Self { $t::atan2(self, other) }
+            fn is_nan(self) -> bool { $t::is_nan(self) }
+            fn is_finite(self) -> bool { $t::is_finite(self) }
+            fn is_infinite(self) -> bool { $t::is_infinite(self) }
+
+            // Infinity and NaN are returned unchanged.
+            fn next_float_up(self) -> Self {
+                if self.is_infinite() && self > 0.0 || self.is_nan() {
+                    return self;
+                }
+
+                // Skip over negative zero so that the result is positive.
+                let v = if self == -0.0 { 0.0 } else { self };
+                let bits = v.to_bits();
+
+                let bits = if v >= 0.0 { bits + 1 } else { bits - 1 };
+
+                $t::from_bits(bits)
+            }
+
+            fn next_float_down(self) -> Self {
+                if self.is_infinite() && self < 0.0 || self.is_nan() {
+                    return self;
+                }
+
+                let v = if self == 0.0 { -0.0 } else { self };
+                let bits = v.to_bits();
+
+                let bits = if v > 0.0 { bits - 1 } else { bits + 1 };
+
+                $t::from_bits(bits)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+// A component type along with the float type that real valued results are returned in,
+// e.g. the length of a Vector3i is a Real and the length of a Vector3<f32> is an f32.
+pub trait Scalar: Copy {
+    type Float: Float;
+
+    fn to_float(self) -> Self::Float;
+}
+
+macro_rules! impl_scalar {
+    ($t:ident => $f:ident) => {
+        impl Scalar for $t {
+            type Float = $f;
+
+            fn to_float(self) -> Self::Float {
+                self as $f
+            }
+        }
+    };
+}
+
+impl_scalar!(f32 => f32);
+impl_scalar!(f64 => f64);
+impl_scalar!(i8 => Real);
+impl_scalar!(i16 => Real);
+impl_scalar!(i32 => Real);
+impl_scalar!(i64 => Real);
+impl_scalar!(u8 => Real);
+impl_scalar!(u16 => Real);
+impl_scalar!(u32 => Real);
+impl_scalar!(u64 => Real);
+
+// A conversion between component types. cast_from behaves like `as`, so float to integer
+// conversions truncate towards zero and saturate. try_cast_from returns None instead if the
+// value can't be represented, e.g. NaN or a float that's out of range of the integer type.
+pub trait CastFrom<T>: Sized {
+    fn cast_from(value: T) -> Self;
+    fn try_cast_from(value: T) -> Option<Self>;
+}
+
+macro_rules! impl_cast_from_int {
+    ($from:ident => $($to:ident),*) => {
+        $(impl CastFrom<$from> for $to {
+            fn cast_from(value: $from) -> Self {
+                value as $to
+            }
+
+            fn try_cast_from(value: $from) -> Option<Self> {
+                std::convert::TryFrom::try_from(value).ok()
+            }
+        })*
+
+        impl CastFrom<$from> for f32 {
+            fn cast_from(value: $from) -> Self {
+                value as f32
+            }
+
+            fn try_cast_from(value: $from) -> Option<Self> {
+                Some(value as f32)
+            }
+        }
+
+        impl CastFrom<$from> for f64 {
+            fn cast_from(value: $from) -> Self {
+                value as f64
+            }
+
+            fn try_cast_from(value: $from) -> Option<Self> {
+                Some(value as f64)
+            }
+        }
+    };
+}
+
+macro_rules! impl_cast_from_float {
+    ($from:ident => $($to:ident),*) => {
+        $(impl CastFrom<$from> for $to {
+            fn cast_from(value: $from) -> Self {
+                value as $to
+            }
+
+            // The bounds are powers of two so they're exact in both float types.
+            fn try_cast_from(value: $from) -> Option<Self> {
+                let value = value.trunc();
+
+                if value >= $to::MIN as $from && value < $to::MAX as $from + 1.0 {
+                    Some(value as $to)
+                } else {
+                    None
+                }
+            }
+        })*
+
+        impl CastFrom<$from> for f32 {
+            fn cast_from(value: $from) -> Self {
+                value as f32
+            }
+
+            fn try_cast_from(value: $from) -> Option<Self> {
+                Some(value as f32)
+            }
+        }
+
+        impl CastFrom<$from> for f64 {
+            fn cast_from(value: $from) -> Self {
+                value as f64
+            }
+
+            fn try_cast_from(value: $from) -> Option<Self> {
+                Some(value as f64)
+            }
+        }
+    };
+}
+
+impl_cast_from_int!(i8 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(i16 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(i32 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(i64 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(u8 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(u16 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(u32 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_int!(u64 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_float!(f32 => i8, i16, i32, i64, u8, u16, u32, u64);
+impl_cast_from_float!(f64 => i8, i16, i32, i64, u8, u16, u32, u64);
+
+// Half the distance between 1.0 and the next representable float, i.e. the
+// maximum relative error introduced by rounding a single operation.
+pub const MACHINE_EPSILON: Real = Real::EPSILON * 0.5;
+
+// A conservative bound on the relative error accumulated over n operations.
+pub fn gamma(n: u32) -> Real {
+    let n_epsilon = n as Real * MACHINE_EPSILON;
+
+    n_epsilon / (1.0 - n_epsilon)
+}
+
+// Returns the smallest float that's greater than v. Infinity and NaN are returned unchanged.
+pub fn next_float_up<F: Float>(v: F) -> F {
+    v.next_float_up()
+}
+
+// Returns the largest float that's less than v. Infinity and NaN are returned unchanged.
+pub fn next_float_down<F: Float>(v: F) -> F {
+    v.next_float_down()
+}
+
+// Like std::cmp::min but for PartialOrd types such as f64. If one of the values is
+// unordered (i.e. NaN) then the other is returned, which matches f64::min.
+pub fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(Ordering::Greater) => b,
+        Some(_) => a,
+        None => if is_unordered(&a) { b } else { a },
+    }
+}
+
+pub fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    match a.partial_cmp(&b) {
+        Some(Ordering::Less) => b,
+        Some(_) => a,
+        None => if is_unordered(&a) { b } else { a },
+    }
+}
+
+fn is_unordered<T: PartialOrd>(a: &T) -> bool {
+    a.partial_cmp(a).is_none()
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,195 @@
+use super::*;
+
+mod gamma {
+    use super::*;
+
+    #[test]
+    fn it_returns_zero_for_zero_operations() {
+        assert_eq!(gamma(0), 0.0);
+    }
+
+    #[test]
+    fn it_returns_slightly_more_than_n_times_machine_epsilon() {
+        let subject = gamma(3);
+
+        assert!(subject > 3.0 * MACHINE_EPSILON);
+        assert!(subject < 3.1 * MACHINE_EPSILON);
+    }
+
+    #[test]
+    fn it_increases_with_the_number_of_operations() {
+        assert!(gamma(5) > gamma(4));
+    }
+}
+
+mod next_float_up {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_next_representable_float() {
+        assert_eq!(next_float_up(1.0), 1.0 + f64::EPSILON);
+        assert!(next_float_up(-1.0) > -1.0);
+    }
+
+    #[test]
+    fn it_steps_from_zero_to_the_smallest_positive_float() {
+        let smallest = f64::from_bits(1);
+
+        assert_eq!(next_float_up(0.0), smallest);
+        assert_eq!(next_float_up(-0.0), smallest);
+    }
+
+    #[test]
+    fn it_does_not_change_positive_infinity_or_nan() {
+        assert_eq!(next_float_up(f64::INFINITY), f64::INFINITY);
+        assert!(next_float_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn it_steps_up_from_negative_infinity() {
+        assert_eq!(next_float_up(f64::NEG_INFINITY), f64::MIN);
+    }
+
+    #[test]
+    fn it_works_for_single_precision_floats() {
+        assert_eq!(next_float_up(1.0_f32), 1.0 + f32::EPSILON);
+        assert_eq!(next_float_up(0.0_f32), f32::from_bits(1));
+    }
+}
+
+mod next_float_down {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_previous_representable_float() {
+        assert_eq!(next_float_down(1.0 + f64::EPSILON), 1.0);
+        assert!(next_float_down(-1.0) < -1.0);
+    }
+
+    #[test]
+    fn it_steps_from_zero_to_the_smallest_negative_float() {
+        let smallest = -f64::from_bits(1);
+
+        assert_eq!(next_float_down(0.0), smallest);
+        assert_eq!(next_float_down(-0.0), smallest);
+    }
+
+    #[test]
+    fn it_does_not_change_negative_infinity_or_nan() {
+        assert_eq!(next_float_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(next_float_down(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn it_is_the_inverse_of_next_float_up() {
+        for &v in [-123.456, -1.0, 0.5, 1e300].iter() {
+            assert_eq!(next_float_down(next_float_up(v)), v);
+        }
+    }
+}
+
+mod scalar {
+    use super::*;
+
+    #[test]
+    fn it_keeps_the_precision_of_floats() {
+        let single: f32 = 1.5_f32.to_float();
+        let double: f64 = 1.5_f64.to_float();
+
+        assert_eq!(single, 1.5);
+        assert_eq!(double, 1.5);
+    }
+
+    #[test]
+    fn it_converts_integers_to_reals() {
+        let subject: Real = 3_i32.to_float();
+
+        assert_eq!(subject, 3.0);
+    }
+}
+
+mod cast_from {
+    use super::*;
+
+    #[test]
+    fn it_converts_like_as() {
+        assert_eq!(i32::cast_from(-2.7_f64), -2);
+        assert_eq!(u8::cast_from(300.0_f32), 255);
+        assert_eq!(f32::cast_from(3_i64), 3.0);
+        assert_eq!(i8::cast_from(300_i32), 44);
+    }
+}
+
+mod try_cast_from {
+    use super::*;
+
+    #[test]
+    fn it_truncates_floats_towards_zero() {
+        assert_eq!(i32::try_cast_from(2.7_f64), Some(2));
+        assert_eq!(i32::try_cast_from(-2.7_f32), Some(-2));
+    }
+
+    #[test]
+    fn it_returns_none_for_nan_and_infinity() {
+        assert_eq!(i32::try_cast_from(f64::NAN), None);
+        assert_eq!(i32::try_cast_from(f64::INFINITY), None);
+        assert_eq!(u64::try_cast_from(f32::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn it_returns_none_if_a_float_is_out_of_range() {
+        assert_eq!(i32::try_cast_from(2_147_483_647.0_f64), Some(i32::MAX));
+        assert_eq!(i32::try_cast_from(2_147_483_648.0_f64), None);
+        assert_eq!(i32::try_cast_from(-2_147_483_648.0_f64), Some(i32::MIN));
+        assert_eq!(i32::try_cast_from(-2_147_483_649.0_f64), None);
+        assert_eq!(u8::try_cast_from(-1.0_f32), None);
+        assert_eq!(u8::try_cast_from(-0.5_f32), Some(0));
+    }
+
+    #[test]
+    fn it_returns_none_if_an_integer_is_out_of_range() {
+        assert_eq!(u8::try_cast_from(255_i32), Some(255));
+        assert_eq!(u8::try_cast_from(256_i32), None);
+        assert_eq!(u32::try_cast_from(-1_i64), None);
+    }
+
+    #[test]
+    fn it_always_converts_to_floats() {
+        assert_eq!(f64::try_cast_from(u64::MAX), Some(u64::MAX as f64));
+        assert_eq!(f32::try_cast_from(0.1_f64), Some(0.1_f32));
+    }
+}
+
+mod partial_min {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_smaller_value() {
+        assert_eq!(partial_min(1.0, 2.0), 1.0);
+        assert_eq!(partial_min(2.0, 1.0), 1.0);
+        assert_eq!(partial_min(3, -3), -3);
+    }
+
+    #[test]
+    fn it_returns_the_other_value_if_one_is_nan() {
+        assert_eq!(partial_min(f64::NAN, 2.0), 2.0);
+        assert_eq!(partial_min(2.0, f64::NAN), 2.0);
+    }
+}
+
+mod partial_max {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_larger_value() {
+        assert_eq!(partial_max(1.0, 2.0), 2.0);
+        assert_eq!(partial_max(2.0, 1.0), 2.0);
+        assert_eq!(partial_max(3, -3), 3);
+    }
+
+    #[test]
+    fn it_returns_the_other_value_if_one_is_nan() {
+        assert_eq!(partial_max(f64::NAN, 2.0), 2.0);
+        assert_eq!(partial_max(2.0, f64::NAN), 2.0);
+    }
+}
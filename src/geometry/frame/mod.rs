@@ -0,0 +1,71 @@
+use super::vector3::Vector3f;
+use super::normal3::Normal3f;
+use super::float::Real;
+
+// An orthonormal basis, e.g. the shading frame at a surface point where z is the normal.
+// Vectors can be converted into the frame's local coordinates and back again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame {
+    pub x: Vector3f,
+    pub y: Vector3f,
+    pub z: Vector3f,
+}
+
+impl Frame {
+    // The vectors are assumed to be orthonormal and right-handed.
+    pub fn new(x: Vector3f, y: Vector3f, z: Vector3f) -> Self {
+        Self { x, y, z }
+    }
+
+    // Completes the basis from a normalized z vector without branching on its components.
+    // See "Building an Orthonormal Basis, Revisited" by Duff et al. (2017).
+    pub fn from_z(z: &Vector3f) -> Self {
+        let sign = Real::copysign(1.0, z.z());
+        let a = -1.0 / (sign + z.z());
+        let b = z.x() * z.y() * a;
+
+        let x = Vector3f::new(1.0 + sign * z.x() * z.x() * a, sign * b, -sign * z.x());
+        let y = Vector3f::new(b, sign + z.y() * z.y() * a, -z.y());
+
+        Self::new(x, y, *z)
+    }
+
+    pub fn from_normal(n: &Normal3f) -> Self {
+        Self::from_z(&Vector3f::from(n))
+    }
+
+    // Builds the frame from two of its normalized, perpendicular vectors.
+    pub fn from_xz(x: &Vector3f, z: &Vector3f) -> Self {
+        Self::new(*x, z.cross(x), *z)
+    }
+
+    pub fn from_xy(x: &Vector3f, y: &Vector3f) -> Self {
+        Self::new(*x, *y, x.cross(y))
+    }
+
+    pub fn to_local(&self, v: &Vector3f) -> Vector3f {
+        Vector3f::new(v.dot(self.x), v.dot(self.y), v.dot(self.z))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_local(&self, v: &Vector3f) -> Vector3f {
+        let mut world = self.x * v.x();
+        world += self.y * v.y();
+        world += self.z * v.z();
+
+        world
+    }
+
+    // The basis is orthonormal so normals transform the same way as vectors.
+    pub fn to_local_normal(&self, n: &Normal3f) -> Normal3f {
+        Normal3f::from(&self.to_local(&Vector3f::from(n)))
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_local_normal(&self, n: &Normal3f) -> Normal3f {
+        Normal3f::from(&self.from_local(&Vector3f::from(n)))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,170 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = Frame;
+
+fn assert_orthonormal(frame: &Frame) {
+    assert_approx!(frame.x.length(), 1.0, epsilon = 1e-6);
+    assert_approx!(frame.y.length(), 1.0, epsilon = 1e-6);
+    assert_approx!(frame.z.length(), 1.0, epsilon = 1e-6);
+
+    assert_approx!(frame.x.dot(frame.y), 0.0, epsilon = 1e-6);
+    assert_approx!(frame.x.dot(frame.z), 0.0, epsilon = 1e-6);
+    assert_approx!(frame.y.dot(frame.z), 0.0, epsilon = 1e-6);
+
+    // Right-handed:
+    assert_approx!(frame.x.cross(&frame.y), frame.z, epsilon = 1e-6);
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_frame_from_three_vectors() {
+        let x = Vector3f::new(1.0, 0.0, 0.0);
+        let y = Vector3f::new(0.0, 1.0, 0.0);
+        let z = Vector3f::new(0.0, 0.0, 1.0);
+
+        let subject = Subject::new(x, y, z);
+
+        assert_eq!(subject.x, x);
+        assert_eq!(subject.y, y);
+        assert_eq!(subject.z, z);
+    }
+}
+
+mod from_z {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_frame_by_calculating_two_orthogonal_vectors() {
+        let vector = Vector3f::new(0.1, 0.2, 0.3).normalize();
+        let subject = Subject::from_z(&vector);
+
+        assert_eq!(subject.z, vector);
+        assert_orthonormal(&subject);
+    }
+
+    #[test]
+    fn it_returns_the_standard_basis_for_the_z_axis() {
+        let subject = Subject::from_z(&Vector3f::new(0.0, 0.0, 1.0));
+
+        assert_eq!(subject.x, Vector3f::new(1.0, 0.0, 0.0));
+        assert_eq!(subject.y, Vector3f::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn it_is_robust_for_vectors_pointing_down_the_z_axis() {
+        let vectors = [
+            Vector3f::new(0.0, 0.0, -1.0),
+            Vector3f::new(1e-8, 0.0, -1.0).normalize(),
+            Vector3f::new(0.0, -1e-8, -1.0).normalize(),
+        ];
+
+        for vector in vectors.iter() {
+            assert_orthonormal(&Subject::from_z(vector));
+        }
+    }
+
+    #[test]
+    fn it_is_orthonormal_for_vectors_in_every_direction() {
+        for i in 0..100 {
+            let theta = i as Real * 0.0317;
+            let phi = i as Real * 0.0721;
+
+            let vector = Vector3f::spherical_direction(theta.sin(), theta.cos(), phi);
+
+            assert_orthonormal(&Subject::from_z(&vector));
+        }
+    }
+}
+
+mod from_normal {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_frame_whose_z_axis_is_the_normal() {
+        let normal = Normal3f::new(1.0, 1.0, 0.0).normalize();
+        let subject = Subject::from_normal(&normal);
+
+        assert_approx!(subject.z, Vector3f::from(&normal), epsilon = 1e-6);
+        assert_orthonormal(&subject);
+    }
+}
+
+mod from_xz {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_frame_from_the_x_and_z_vectors() {
+        let x = Vector3f::new(0.0, 1.0, 0.0);
+        let z = Vector3f::new(1.0, 0.0, 0.0);
+
+        let subject = Subject::from_xz(&x, &z);
+
+        assert_approx!(subject.y, Vector3f::new(0.0, 0.0, 1.0), epsilon = 1e-6);
+        assert_orthonormal(&subject);
+    }
+}
+
+mod from_xy {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_frame_from_the_x_and_y_vectors() {
+        let x = Vector3f::new(0.0, 1.0, 0.0);
+        let y = Vector3f::new(0.0, 0.0, 1.0);
+
+        let subject = Subject::from_xy(&x, &y);
+
+        assert_approx!(subject.z, Vector3f::new(1.0, 0.0, 0.0), epsilon = 1e-6);
+        assert_orthonormal(&subject);
+    }
+}
+
+mod to_local {
+    use super::*;
+
+    #[test]
+    fn it_converts_a_vector_into_the_frames_coordinates() {
+        let subject = Subject::from_xy(&Vector3f::new(0.0, 1.0, 0.0), &Vector3f::new(0.0, 0.0, 1.0));
+        let local = subject.to_local(&Vector3f::new(1.0, 2.0, 3.0));
+
+        assert_approx!(local, Vector3f::new(2.0, 3.0, 1.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_maps_the_z_axis_to_the_local_z_axis() {
+        let z = Vector3f::new(1.0, -2.0, 0.5).normalize();
+        let subject = Subject::from_z(&z);
+
+        assert_approx!(subject.to_local(&z), Vector3f::new(0.0, 0.0, 1.0), epsilon = 1e-6);
+    }
+}
+
+mod from_local {
+    use super::*;
+
+    #[test]
+    fn it_is_the_inverse_of_to_local() {
+        let subject = Subject::from_z(&Vector3f::new(-0.3, 0.4, 0.5).normalize());
+        let vector = Vector3f::new(1.0, 2.0, 3.0);
+
+        assert_approx!(subject.from_local(&subject.to_local(&vector)), vector, epsilon = 1e-6);
+    }
+}
+
+mod normals {
+    use super::*;
+
+    #[test]
+    fn it_converts_normals_to_and_from_local_coordinates() {
+        let subject = Subject::from_xy(&Vector3f::new(0.0, 1.0, 0.0), &Vector3f::new(0.0, 0.0, 1.0));
+
+        let local = subject.to_local_normal(&Normal3f::new(1.0, 2.0, 3.0));
+        let world = subject.from_local_normal(&local);
+
+        assert_eq!((local.x(), local.y(), local.z()), (2.0, 3.0, 1.0));
+        assert_eq!((world.x(), world.y(), world.z()), (1.0, 2.0, 3.0));
+    }
+}
@@ -0,0 +1,164 @@
+use std::ops::Mul;
+use std::fmt;
+use std::error::Error;
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::float::Real;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix4x4 {
+    pub m: [[Real; 4]; 4],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SingularMatrix;
+
+impl fmt::Display for SingularMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the matrix is singular and cannot be inverted")
+    }
+}
+
+impl Error for SingularMatrix { }
+
+impl Matrix4x4 {
+    pub fn new(m: [[Real; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    pub fn identity() -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut m = [[0.0; 4]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.m[j][i];
+            }
+        }
+
+        Self::new(m)
+    }
+
+    // Gauss-Jordan elimination with full pivoting for numerical stability.
+    pub fn inverse(&self) -> Result<Self, SingularMatrix> {
+        let mut minv = self.m;
+        let mut indxc = [0; 4];
+        let mut indxr = [0; 4];
+        let mut ipiv = [0; 4];
+
+        for i in 0..4 {
+            let mut irow = 0;
+            let mut icol = 0;
+            let mut big = 0.0;
+
+            // Choose the pivot with the largest magnitude that hasn't been used yet.
+            for j in 0..4 {
+                if ipiv[j] == 1 {
+                    continue;
+                }
+
+                for k in 0..4 {
+                    if ipiv[k] == 0 {
+                        if minv[j][k].abs() >= big {
+                            big = minv[j][k].abs();
+                            irow = j;
+                            icol = k;
+                        }
+                    } else if ipiv[k] > 1 {
+                        return Err(SingularMatrix);
+                    }
+                }
+            }
+
+            ipiv[icol] += 1;
+
+            // Swap rows so the pivot is on the diagonal.
+            if irow != icol {
+                minv.swap(irow, icol);
+            }
+
+            indxr[i] = irow;
+            indxc[i] = icol;
+
+            if minv[icol][icol] == 0.0 {
+                return Err(SingularMatrix);
+            }
+
+            // Scale the pivot row so the pivot is one.
+            let pivinv = minv[icol][icol].recip();
+            minv[icol][icol] = 1.0;
+
+            for value in minv[icol].iter_mut() {
+                *value *= pivinv;
+            }
+
+            // Subtract the pivot row from the others to zero the pivot column.
+            for j in 0..4 {
+                if j == icol {
+                    continue;
+                }
+
+                let pivot_row = minv[icol];
+                let save = minv[j][icol];
+                minv[j][icol] = 0.0;
+
+                for (value, pivot) in minv[j].iter_mut().zip(pivot_row.iter()) {
+                    *value -= pivot * save;
+                }
+            }
+        }
+
+        // Undo the column swaps in reverse order.
+        for j in (0..4).rev() {
+            if indxr[j] != indxc[j] {
+                for row in minv.iter_mut() {
+                    row.swap(indxr[j], indxc[j]);
+                }
+            }
+        }
+
+        Ok(Self::new(minv))
+    }
+}
+
+impl Default for Matrix4x4 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl ApproxEq for Matrix4x4 {
+    type Float = Real;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Real>) -> bool {
+        self.m.iter().flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl Mul<Self> for &Matrix4x4 {
+    type Output = Matrix4x4;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let mut m = [[0.0; 4]; 4];
+
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.m[i][k] * other.m[k][j]).sum();
+            }
+        }
+
+        Matrix4x4::new(m)
+    }
+}
+
+#[cfg(test)]
+mod test;
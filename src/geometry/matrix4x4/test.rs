@@ -0,0 +1,158 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = Matrix4x4;
+
+fn example() -> Subject {
+    Subject::new([
+        [2.0, 0.0, 0.0, 1.0],
+        [0.0, 3.0, 1.0, 2.0],
+        [1.0, 0.0, 4.0, 3.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_matrix_from_rows_of_values() {
+        let subject = example();
+
+        assert_eq!(subject.m[0], [2.0, 0.0, 0.0, 1.0]);
+        assert_eq!(subject.m[1][2], 1.0);
+        assert_eq!(subject.m[2][0], 1.0);
+    }
+}
+
+mod identity {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_matrix_with_ones_on_the_diagonal() {
+        let subject = Subject::identity();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(subject.m[i][j], if i == j { 1.0 } else { 0.0 });
+            }
+        }
+    }
+}
+
+mod default {
+    use super::*;
+
+    #[test]
+    fn it_builds_the_identity_matrix() {
+        assert_eq!(Subject::default(), Subject::identity());
+    }
+}
+
+mod equality {
+    use super::*;
+
+    #[test]
+    fn it_compares_matrices_by_their_values() {
+        assert_eq!(example(), example());
+        assert_ne!(example(), Subject::identity());
+    }
+}
+
+mod debug {
+    use super::*;
+
+    #[test]
+    fn it_formats_the_matrix_for_debugging() {
+        let subject = format!("{:?}", Subject::identity());
+
+        assert!(subject.starts_with("Matrix4x4 { m: [[1.0, 0.0, 0.0, 0.0]"));
+    }
+}
+
+mod transpose {
+    use super::*;
+
+    #[test]
+    fn it_swaps_the_rows_and_columns() {
+        let subject = example().transpose();
+
+        assert_eq!(subject.m[0], [2.0, 0.0, 1.0, 0.0]);
+        assert_eq!(subject.m[3], [1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn it_returns_the_original_matrix_if_transposed_twice() {
+        assert_eq!(example().transpose().transpose(), example());
+    }
+}
+
+mod multiplication {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_two_matrices() {
+        let a = example();
+        let b = example().transpose();
+
+        let subject = &a * &b;
+
+        assert_eq!(subject.m[0], [5.0, 2.0, 5.0, 1.0]);
+        assert_eq!(subject.m[1], [2.0, 14.0, 10.0, 2.0]);
+        assert_eq!(subject.m[2], [5.0, 10.0, 26.0, 3.0]);
+        assert_eq!(subject.m[3], [1.0, 2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn it_returns_the_same_matrix_when_multiplied_by_the_identity() {
+        let identity = Subject::identity();
+
+        assert_eq!(&example() * &identity, example());
+        assert_eq!(&identity * &example(), example());
+    }
+}
+
+mod inverse {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_matrix_that_multiplies_with_the_original_to_give_the_identity() {
+        let subject = example().inverse().unwrap();
+
+        assert_approx!(&example() * &subject, Subject::identity(), epsilon = 1e-6);
+        assert_approx!(&subject * &example(), Subject::identity(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_inverts_a_matrix_that_needs_pivoting() {
+        let matrix = Subject::new([
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 2.0],
+        ]);
+
+        let subject = matrix.inverse().unwrap();
+
+        assert_approx!(&matrix * &subject, Subject::identity(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_matrix_is_singular() {
+        let matrix = Subject::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        assert_eq!(matrix.inverse(), Err(SingularMatrix));
+    }
+
+    #[test]
+    fn it_returns_an_error_for_the_zero_matrix() {
+        let matrix = Subject::new([[0.0; 4]; 4]);
+
+        assert_eq!(matrix.inverse(), Err(SingularMatrix));
+    }
+}
@@ -1,8 +1,23 @@
+mod float;
+mod approx_eq;
+mod efloat;
+mod dimension_mismatch;
+mod parse_geometry_error;
+mod ops;
+
+#[cfg(feature = "serde")]
+mod serde_components;
+
 mod vector;
 mod vector2;
 mod vector3;
 
-mod coordinate_system;
+mod frame;
+
+mod matrix4x4;
+mod transform;
+mod quaternion;
+mod animated_transform;
 
 mod point;
 mod point2;
@@ -17,3 +32,38 @@ mod ray_differential;
 mod bounds;
 mod bounds2;
 mod bounds3;
+mod bounds2_iterator;
+
+// The submodules are private so that the types can be reorganised without breaking
+// anything that depends on the crate. Everything public is re-exported from here.
+pub use self::float::{Real, Float, Scalar, CastFrom, MACHINE_EPSILON, gamma, next_float_up, next_float_down, partial_min, partial_max};
+pub use self::approx_eq::{ApproxEq, Tolerance};
+pub use self::efloat::{EFloat, quadratic};
+pub use self::dimension_mismatch::DimensionMismatch;
+pub use self::parse_geometry_error::ParseGeometryError;
+
+pub use self::vector::Vector;
+pub use self::vector2::{Vector2, Vector2f, Vector2i};
+pub use self::vector3::{Vector3, Vector3f, Vector3i};
+
+pub use self::frame::Frame;
+
+pub use self::matrix4x4::{Matrix4x4, SingularMatrix};
+pub use self::transform::Transform;
+pub use self::quaternion::Quaternion;
+pub use self::animated_transform::AnimatedTransform;
+
+pub use self::point::Point;
+pub use self::point2::{Point2, Point2f, Point2i};
+pub use self::point3::{Point3, Point3f, Point3i};
+
+pub use self::normal::Normal;
+pub use self::normal3::{Normal3, Normal3f};
+
+pub use self::ray::{Ray, SHADOW_EPSILON, offset_ray_origin};
+pub use self::ray_differential::RayDifferential;
+
+pub use self::bounds::Bounds;
+pub use self::bounds2::{Bounds2, Bounds2f, Bounds2i};
+pub use self::bounds3::{Bounds3, Bounds3f, Bounds3i};
+pub use self::bounds2_iterator::{Pixels, Tiles};
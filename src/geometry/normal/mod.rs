@@ -1,132 +1,278 @@
-use generic_array::{ArrayLength, GenericArray};
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Index, IndexMut};
 use std::iter::Sum;
 use super::vector::Vector;
+use super::ops::{forward_ref_binop, forward_ref_op_assign, impl_scalar_mul};
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::parse_geometry_error::{ParseGeometryError, components_from_str, fmt_components};
+use super::dimension_mismatch::{DimensionMismatch, components_from_vec, components_from_slice};
+use super::float::{Float, Scalar, CastFrom};
 
-#[derive(Default)]
-pub struct Normal<T, N: ArrayLength<T>> {
-    pub components: GenericArray<T, N>,
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+#[cfg(feature = "serde")]
+use super::serde_components;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Normal<T, const N: usize> {
+    pub components: [T; N],
 }
 
-// Cloning GenericArray doesn't seem to work properly, so implement it manually:
-impl<T: Clone, N: ArrayLength<T>> Clone for Normal<T, N> {
-    fn clone(&self) -> Self {
-        self.components.iter().cloned().into()
+// Arrays only implement Default up to a fixed size, so it can't be derived for any N.
+impl<T: Default, const N: usize> Default for Normal<T, N> {
+    fn default() -> Self {
+        Self::generate(|_| T::default())
     }
 }
 
-impl<T, N: ArrayLength<T>, I, X> From<I> for Normal<T, N>
-    where I: IntoIterator<Item=T, IntoIter=X>,
-          X: ExactSizeIterator<Item=T>,
-{
-    fn from(iter: I) -> Self {
-        Self { components: GenericArray::from_exact_iter(iter).unwrap() }
+impl<T, const N: usize> Normal<T, N> {
+    // Builds the normal from a function of each component's index.
+    pub fn generate<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self { components: std::array::from_fn(f) }
+    }
+
+    pub fn try_from_iter<I: IntoIterator<Item=T>>(iter: I) -> Result<Self, DimensionMismatch> {
+        Self::try_from(iter.into_iter().collect::<Vec<_>>())
     }
 }
 
-impl <T: Clone, N: ArrayLength<T>> From<&Normal<T, N>> for Normal<T, N> {
+impl<T, const N: usize> From<[T; N]> for Normal<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self { components }
+    }
+}
+
+// Indexes the components by axis, e.g. normal[0] is the x component.
+impl<T, const N: usize> Index<usize> for Normal<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.components[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Normal<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.components[axis]
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for Normal<T, N> {
+    type Error = DimensionMismatch;
+
+    fn try_from(components: Vec<T>) -> Result<Self, Self::Error> {
+        Ok(Self { components: components_from_vec(components)? })
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for Normal<T, N> {
+    type Error = DimensionMismatch;
+
+    fn try_from(components: &[T]) -> Result<Self, Self::Error> {
+        Ok(Self { components: components_from_slice(components)? })
+    }
+}
+
+// Converts the components to another type, e.g. a Normal2i to a Normal2f. The try_ version
+// returns None if a component can't be represented, e.g. a NaN float cast to an integer.
+impl<T: Copy, const N: usize> Normal<T, N> {
+    pub fn cast<U: CastFrom<T>>(&self) -> Normal<U, N> {
+        Normal { components: self.components.map(U::cast_from) }
+    }
+
+    pub fn try_cast<U: CastFrom<T> + Copy>(&self) -> Option<Normal<U, N>> {
+        let components = self.components.map(U::try_cast_from);
+
+        match components.iter().all(Option::is_some) {
+            true => Some(Normal::generate(|i| components[i].unwrap())),
+            false => None,
+        }
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for Normal<T, N> {
+    type Float = T::Float;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Float>) -> bool {
+        self.components.iter()
+            .zip(other.components.iter())
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for Normal<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_components(&self.components, f)
+    }
+}
+
+impl<T: FromStr, const N: usize> FromStr for Normal<T, N> {
+    type Err = ParseGeometryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { components: components_from_str(s)? })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize, const N: usize> Serialize for Normal<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_components::serialize(&self.components, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Normal<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self { components: serde_components::deserialize(deserializer)? })
+    }
+}
+
+impl <T: Clone, const N: usize> From<&Normal<T, N>> for Normal<T, N> {
     fn from(normal: &Normal<T, N>) -> Self {
         normal.clone()
     }
 }
 
-impl <T: Clone, N: ArrayLength<T>> From<&Vector<T, N>> for Normal<T, N> {
+impl <T: Clone, const N: usize> From<&Vector<T, N>> for Normal<T, N> {
     fn from(vector: &Vector<T, N>) -> Self {
-        vector.components.iter().cloned().into()
+        Normal::generate(|i| vector.components[i].clone())
     }
 }
 
-impl<T: Add<Output=T> + Copy, N: ArrayLength<T>> Add<Self> for &Normal<T, N> {
+impl<T: Add<Output=T> + Copy, const N: usize> Add for Normal<T, N> {
     type Output = Normal<T, N>;
 
     fn add(self, other: Self) -> Self::Output {
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(a, b)| *a + *b)
-            .into()
+        Normal::generate(|i| self.components[i] + other.components[i])
     }
 }
 
-impl<T: AddAssign + Copy, N: ArrayLength<T>> AddAssign<&Self> for Normal<T, N> {
-    fn add_assign(&mut self, other: &Self) {
+forward_ref_binop!(Add, add, Normal, Normal);
+
+impl<T: AddAssign + Copy, const N: usize> AddAssign for Normal<T, N> {
+    fn add_assign(&mut self, other: Self) {
         self.components.iter_mut()
             .zip(other.components.iter())
             .for_each(|(a, b)| *a += *b);
     }
 }
 
-impl<T: Sub<Output=T> + Copy, N: ArrayLength<T>> Sub<Self> for &Normal<T, N> {
+forward_ref_op_assign!(AddAssign, add_assign, Normal, Normal);
+
+impl<T: Sub<Output=T> + Copy, const N: usize> Sub for Normal<T, N> {
     type Output = Normal<T, N>;
 
     fn sub(self, other: Self) -> Self::Output {
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(a, b)| *a - *b)
-            .into()
+        Normal::generate(|i| self.components[i] - other.components[i])
     }
 }
 
-impl<T: SubAssign + Copy, N: ArrayLength<T>> SubAssign<&Self> for Normal<T, N> {
-    fn sub_assign(&mut self, other: &Self) {
+forward_ref_binop!(Sub, sub, Normal, Normal);
+
+impl<T: SubAssign + Copy, const N: usize> SubAssign for Normal<T, N> {
+    fn sub_assign(&mut self, other: Self) {
         self.components.iter_mut()
             .zip(other.components.iter())
             .for_each(|(a, b)| *a -= *b);
     }
 }
 
-impl<T: Mul<Output=T> + Copy, N: ArrayLength<T>> Mul<T> for &Normal<T, N> {
+forward_ref_op_assign!(SubAssign, sub_assign, Normal, Normal);
+
+impl<T: Mul<Output=T> + Copy, const N: usize> Mul<T> for Normal<T, N> {
+    type Output = Normal<T, N>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Normal::generate(|i| self.components[i] * scalar)
+    }
+}
+
+impl<T: Mul<Output=T> + Copy, const N: usize> Mul<T> for &Normal<T, N> {
     type Output = Normal<T, N>;
 
     fn mul(self, scalar: T) -> Self::Output {
-        self.components.iter().map(|a| *a * scalar).into()
+        *self * scalar
     }
 }
 
-impl<T: MulAssign + Copy, N: ArrayLength<T>> MulAssign<T> for Normal<T, N> {
+impl_scalar_mul!(Normal: f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<T: MulAssign + Copy, const N: usize> MulAssign<T> for Normal<T, N> {
     fn mul_assign(&mut self, scalar: T) {
         self.components.iter_mut().for_each(|a| *a *= scalar);
     }
 }
 
-impl<T: Into<f64> + Copy, D: Into<f64>, N> Div<D> for &Normal<T, N>
-    where N: ArrayLength<T> + ArrayLength<f64>,
-{
-    type Output = Normal<f64, N>;
+// The component-wise product and quotient, e.g. for scaling each axis by a different amount.
+impl<T: Mul<Output=T> + Copy, const N: usize> Normal<T, N> {
+    pub fn hadamard_product(&self, other: &Self) -> Self {
+        Normal::generate(|i| self.components[i] * other.components[i])
+    }
+}
+
+impl<T: Div<Output=T> + Copy, const N: usize> Normal<T, N> {
+    pub fn hadamard_quotient(&self, other: &Self) -> Self {
+        Normal::generate(|i| self.components[i] / other.components[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T::Float> for Normal<T, N> {
+    type Output = Normal<T::Float, N>;
 
-    fn div(self, divisor: D) -> Self::Output {
-        let inverse = divisor.into().recip();
+    fn div(self, divisor: T::Float) -> Self::Output {
+        let inverse = divisor.recip();
 
-        self.components.iter().map(|&a| a.into() * inverse).into()
+        Normal::generate(|i| self.components[i].to_float() * inverse)
     }
 }
 
-impl<D: Into<f64>, N: ArrayLength<f64>> DivAssign<D> for Normal<f64, N> {
-    fn div_assign(&mut self, divisor: D) {
-        let inverse = divisor.into().recip();
+impl<T: Scalar, const N: usize> Div<T::Float> for &Normal<T, N> {
+    type Output = Normal<T::Float, N>;
+
+    fn div(self, divisor: T::Float) -> Self::Output {
+        *self / divisor
+    }
+}
+
+impl<F: Float, const N: usize> DivAssign<F> for Normal<F, N> {
+    fn div_assign(&mut self, divisor: F) {
+        let inverse = divisor.recip();
 
         self.components.iter_mut().for_each(|a| *a *= inverse);
     }
 }
 
-impl<T: Neg<Output=T> + Copy, N: ArrayLength<T>> Neg for &Normal<T, N> {
+impl<T: Neg<Output=T> + Copy, const N: usize> Neg for Normal<T, N> {
+    type Output = Normal<T, N>;
+
+    fn neg(self) -> Self::Output {
+        Normal::generate(|i| -self.components[i])
+    }
+}
+
+impl<T: Neg<Output=T> + Copy, const N: usize> Neg for &Normal<T, N> {
     type Output = Normal<T, N>;
 
     fn neg(self) -> Self::Output {
-        self.components.iter().map(|&a| -a).into()
+        -*self
     }
 }
 
-impl<N: ArrayLength<f64>> Normal<f64, N> {
+impl<F: Float, const N: usize> Normal<F, N> {
     pub fn abs(&self) -> Self {
-        self.components.iter().map(|&a| a.abs()).into()
+        Normal::generate(|i| self.components[i].abs())
     }
 
-    pub fn abs_dot<S: Into<Self>>(&self, other: S) -> f64 {
+    pub fn abs_dot<S: Into<Self>>(&self, other: S) -> F {
         self.dot(other).abs()
     }
 }
 
-impl<T: Mul<Output=T> + Sum + Copy, N: ArrayLength<T>> Normal<T, N> {
+impl<T: Mul<Output=T> + Sum + Copy, const N: usize> Normal<T, N> {
     pub fn dot<S: Into<Self>>(&self, other: S) -> T {
         let other: Self = other.into();
 
@@ -137,25 +283,25 @@ impl<T: Mul<Output=T> + Sum + Copy, N: ArrayLength<T>> Normal<T, N> {
     }
 }
 
-impl<T: Into<f64> + Copy, N: ArrayLength<T>> Normal<T, N> {
-    pub fn length_squared(&self) -> f64 {
-        self.components.iter().map(|&a| a.into().powf(2.0)).sum()
+impl<T: Scalar, const N: usize> Normal<T, N> {
+    pub fn length_squared(&self) -> T::Float {
+        self.components.iter().map(|&a| a.to_float() * a.to_float()).sum()
     }
 
-    pub fn length(&self) -> f64 {
+    pub fn length(&self) -> T::Float {
         self.length_squared().sqrt()
     }
 }
 
-impl<T: Into<f64> + Copy, N: ArrayLength<T> + ArrayLength<f64>> Normal<T, N> {
-    pub fn normalize(&self) -> Normal<f64, N> {
+impl<T: Scalar, const N: usize> Normal<T, N> {
+    pub fn normalize(&self) -> Normal<T::Float, N> {
         self / self.length()
     }
 }
 
-impl<T, N: ArrayLength<T>> Normal<T, N>
+impl<T, const N: usize> Normal<T, N>
     where T: Mul<Output=T> + Sum + Copy, // To satisfy dot.
-          T: Ord + Default,              // For the comparison.
+          T: PartialOrd + Default,       // For the comparison.
           T: Neg<Output=T>               // To satisfy neg.
 {
     pub fn face_forward<S: Into<Self>>(&self, other: S) -> Self {
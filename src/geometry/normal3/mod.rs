@@ -1,11 +1,11 @@
-use generic_array::{arr, arr_impl, typenum::U3};
 use super::normal::*;
+use super::float::Real;
 
-pub type Normal3<T> = Normal<T, U3>;
+pub type Normal3<T> = Normal<T, 3>;
 
 impl<T: Copy> Normal3<T> {
     pub fn new(x: T, y: T, z: T) -> Self {
-        Self { components: arr![T; x, y, z] }
+        Self { components: [x, y, z] }
     }
 
     pub fn x(&self) -> T {
@@ -21,7 +21,7 @@ impl<T: Copy> Normal3<T> {
     }
 }
 
-pub type Normal3f = Normal3<f64>;
+pub type Normal3f = Normal3<Real>;
 
 #[cfg(test)]
 mod test;
@@ -1,5 +1,7 @@
 use super::*;
-use assert_approx_eq::assert_approx_eq;
+use crate::assert_approx;
+use std::convert::TryFrom;
+use crate::geometry::dimension_mismatch::DimensionMismatch;
 use crate::geometry::vector3::Vector3;
 
 type Subject<T> = Normal3<T>;
@@ -33,8 +35,8 @@ mod aliases {
     use super::*;
 
     #[test]
-    fn it_has_a_type_alias_for_a_normal_of_double_precision_floats() {
-        Normal3f::new(0.1 as f64, 0.2, 0.3);
+    fn it_has_a_type_alias_for_a_normal_of_reals() {
+        Normal3f::new(0.1 as Real, 0.2, 0.3);
     }
 }
 
@@ -51,16 +53,18 @@ mod default {
     }
 }
 
-mod clone {
+mod copy {
     use super::*;
 
     #[test]
-    fn it_clones_the_normal() {
-        let subject = Subject::new(1, 2, 3).clone();
+    fn it_copies_the_normal() {
+        let original = Subject::new(1, 2, 3);
+        let subject = original;
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
+        assert_eq!(original.x(), 1);
     }
 }
 
@@ -68,14 +72,46 @@ mod conversions {
     use super::*;
 
     #[test]
-    fn it_can_build_a_normal3_from_an_iterator() {
-        let subject: Subject<_> = (1..4).into();
+    fn it_can_build_a_normal3_from_an_array() {
+        let subject: Subject<_> = [1, 2, 3].into();
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
 
+    #[test]
+    fn it_can_try_to_build_a_normal3_from_a_slice() {
+        let subject = Subject::try_from(&[1, 2, 3][..]).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result = Subject::try_from(&[1, 2][..]);
+
+        assert_eq!(result.err(), Some(DimensionMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn it_can_try_to_build_a_normal3_from_an_iterator() {
+        let subject = Subject::try_from_iter(1..4).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_iterator_has_the_wrong_length() {
+        let result = Subject::<i32>::try_from_iter(1..10);
+
+        assert_eq!(result.err(), Some(DimensionMismatch { expected: 3, actual: 9 }));
+    }
+
     #[test]
     fn it_can_build_a_normal3_from_a_vector3() {
         let vector = &Vector3::new(1, 2, 3);
@@ -87,6 +123,26 @@ mod conversions {
     }
 }
 
+mod cast {
+    use super::*;
+
+    #[test]
+    fn it_converts_the_components_to_another_type() {
+        let subject = Normal3::new(1, -2, 3).cast::<f32>();
+
+        assert_eq!(subject.x(), 1.0);
+        assert_eq!(subject.y(), -2.0);
+        assert_eq!(subject.z(), 3.0);
+    }
+
+    #[test]
+    fn it_returns_none_if_a_component_cannot_be_represented() {
+        let subject = Normal3::new(0.0, Real::NAN, 1.0).try_cast::<i32>();
+
+        assert!(subject.is_none());
+    }
+}
+
 mod addition {
     use super::*;
 
@@ -95,7 +151,7 @@ mod addition {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(4, 5, 6);
 
-        let subject = &a + &b;
+        let subject = a + b;
 
         assert_eq!(subject.x(), 5);
         assert_eq!(subject.y(), 7);
@@ -124,7 +180,7 @@ mod subtraction {
         let a = Subject::new(5, 5, 5);
         let b = Subject::new(1, 2, 3);
 
-        let subject = &a - &b;
+        let subject = a - b;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 3);
@@ -174,22 +230,22 @@ mod division {
 
     #[test]
     fn it_divides_by_a_divisor() {
-        let subject = &Subject::new(1, 2, 3) / 10;
+        let subject = &Subject::<i32>::new(1, 2, 3) / 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 
     #[test]
     fn it_can_mutate_the_normal() {
-        let mut subject = Subject::new(1.0, 2.0, 3.0);
+        let mut subject = Subject::<f64>::new(1.0, 2.0, 3.0);
 
-        subject /= 10;
+        subject /= 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 }
 
@@ -227,7 +283,7 @@ mod dot {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(4, 5, 6);
 
-        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.dot(b), 32);
     }
 
     #[test]
@@ -247,7 +303,7 @@ mod abs_dot {
         let a = Subject::new(1.0, 2.0, 3.0);
         let b = Subject::new(-4.0, -5.0, -6.0);
 
-        assert_eq!(a.abs_dot(&b), 32.0);
+        assert_eq!(a.abs_dot(b), 32.0);
     }
 
     #[test]
@@ -266,7 +322,7 @@ mod length_squared {
     fn it_returns_the_square_of_the_length_of_the_normal() {
         let subject = Subject::new(1, 2, 3);
 
-        assert_eq!(subject.length_squared(), 14.0);
+        assert_eq!(subject.length_squared(), 14.0 as Real);
     }
 }
 
@@ -277,7 +333,7 @@ mod length {
     fn it_returns_the_length_of_the_normal() {
         let subject = Subject::new(1, 2, 3);
 
-        assert_eq!(subject.length(), f64::sqrt(14.0));
+        assert_eq!(subject.length(), Real::sqrt(14.0));
     }
 }
 
@@ -287,7 +343,7 @@ mod normalize {
     #[test]
     fn it_divides_each_component_by_the_normals_length() {
         let subject = Subject::new(1, 2, 3).normalize();
-        let divisor = f64::sqrt(14.0);
+        let divisor = Real::sqrt(14.0);
 
         assert_eq!(subject.x(), 1.0 / divisor);
         assert_eq!(subject.y(), 2.0 / divisor);
@@ -303,7 +359,7 @@ mod face_forward {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(-5, -5, -5);
 
-        let subject = a.face_forward(&b);
+        let subject = a.face_forward(b);
 
         assert_eq!(subject.x(), -1);
         assert_eq!(subject.y(), -2);
@@ -321,4 +377,135 @@ mod face_forward {
         assert_eq!(subject.y(), -2);
         assert_eq!(subject.z(), -3);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let normal = Normal3f::new(0.0, 0.0, 1.0);
+        let vector = Vector3::new(0.3, 0.2, -0.1);
+
+        let subject = normal.face_forward(&vector);
+
+        assert_eq!(subject.z(), -1.0);
+    }
+
+    #[test]
+    fn it_leaves_the_normal_unchanged_if_the_dot_product_is_nan() {
+        let normal = Normal3f::new(0.0, 0.0, 1.0);
+        let vector = Vector3::new(0.0, 0.0, Real::NAN);
+
+        let subject = normal.face_forward(&vector);
+
+        assert_eq!(subject.z(), 1.0);
+    }
+}
+
+mod approx_eq {
+    use super::*;
+    use crate::geometry::approx_eq::ApproxEq;
+
+    #[test]
+    fn it_compares_each_component() {
+        let subject = Normal3::new(0.0, 1.0, 0.0);
+
+        assert!(subject.ulps_eq(&Normal3::new(0.0, 1.0 + Real::EPSILON, 0.0), 1));
+        assert!(!subject.ulps_eq(&Normal3::new(0.0, 1.0, 0.001), 4));
+    }
+
+    #[test]
+    fn it_can_be_asserted() {
+        assert_approx!(Normal3::new(3.0, 0.0, 4.0).normalize(), Normal3::new(0.6, 0.0, 0.8));
+    }
+}
+
+mod debug {
+    use super::*;
+
+    #[test]
+    fn it_shows_the_components() {
+        assert_eq!(format!("{:?}", Normal3::new(1, 2, 3)), "Normal { components: [1, 2, 3] }");
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn it_formats_the_components_in_parentheses() {
+        assert_eq!(Normal3::new(0, 1, 0).to_string(), "(0, 1, 0)");
+    }
+}
+
+mod from_str {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_displayed_format() {
+        let subject: Normal3f = "(0, 0.5, -1)".parse().unwrap();
+
+        assert_eq!(subject.components, [0.0, 0.5, -1.0]);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_string_is_malformed() {
+        assert!("0, 0, 1".parse::<Normal3f>().is_err());
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_through_json() {
+        let json = serde_json::to_string(&Normal3::new(0.0, 1.0, 0.0)).unwrap();
+        let subject: Normal3f = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "[0.0,1.0,0.0]");
+        assert_eq!(subject.components, [0.0, 1.0, 0.0]);
+    }
+}
+
+mod mixed_operands {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_can_mix_owned_and_borrowed_operands() {
+        let a = Subject::new(1, 2, 3);
+        let b = Subject::new(1, 1, 1);
+
+        assert_eq!((&a + b).components, [2, 3, 4]);
+        assert_eq!((a - &b).components, [0, 1, 2]);
+    }
+
+    #[test]
+    fn it_multiplies_a_scalar_by_a_normal() {
+        assert_eq!((3 * Subject::<i32>::new(1, 2, 3)).components, [3, 6, 9]);
+    }
+}
+
+mod index {
+    use super::*;
+
+    #[test]
+    fn it_indexes_the_components_by_axis() {
+        let mut subject = Subject::new(0.0, 1.0, 0.0);
+        subject[2] = -1.0;
+
+        assert_eq!(subject[1], 1.0);
+        assert_eq!(subject.components, [0.0, 1.0, -1.0]);
+    }
+}
+
+mod hadamard {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_and_divides_the_components_pairwise() {
+        let subject = Subject::new(2, 4, 6);
+        let scale = Subject::new(2, 2, 3);
+
+        assert_eq!(subject.hadamard_product(&scale).components, [4, 8, 18]);
+        assert_eq!(subject.hadamard_quotient(&scale).components, [1, 2, 2]);
+    }
 }
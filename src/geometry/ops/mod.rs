@@ -0,0 +1,76 @@
+// The arithmetic on points, vectors and normals is implemented once on owned values. These
+// forward every combination of owned and borrowed operands to that implementation, which is
+// cheap because the types are Copy.
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $lhs:ident, $rhs:ident) => {
+        impl<T: Copy, const N: usize> ::std::ops::$imp<&$rhs<T, N>> for $lhs<T, N>
+            where $lhs<T, N>: ::std::ops::$imp<$rhs<T, N>>,
+        {
+            type Output = <$lhs<T, N> as ::std::ops::$imp<$rhs<T, N>>>::Output;
+
+            fn $method(self, other: &$rhs<T, N>) -> Self::Output {
+                ::std::ops::$imp::$method(self, *other)
+            }
+        }
+
+        impl<T: Copy, const N: usize> ::std::ops::$imp<$rhs<T, N>> for &$lhs<T, N>
+            where $lhs<T, N>: ::std::ops::$imp<$rhs<T, N>>,
+        {
+            type Output = <$lhs<T, N> as ::std::ops::$imp<$rhs<T, N>>>::Output;
+
+            fn $method(self, other: $rhs<T, N>) -> Self::Output {
+                ::std::ops::$imp::$method(*self, other)
+            }
+        }
+
+        impl<T: Copy, const N: usize> ::std::ops::$imp<&$rhs<T, N>> for &$lhs<T, N>
+            where $lhs<T, N>: ::std::ops::$imp<$rhs<T, N>>,
+        {
+            type Output = <$lhs<T, N> as ::std::ops::$imp<$rhs<T, N>>>::Output;
+
+            fn $method(self, other: &$rhs<T, N>) -> Self::Output {
+                ::std::ops::$imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_op_assign {
+    ($imp:ident, $method:ident, $lhs:ident, $rhs:ident) => {
+        impl<T: Copy, const N: usize> ::std::ops::$imp<&$rhs<T, N>> for $lhs<T, N>
+            where $lhs<T, N>: ::std::ops::$imp<$rhs<T, N>>,
+        {
+            fn $method(&mut self, other: &$rhs<T, N>) {
+                ::std::ops::$imp::$method(self, *other)
+            }
+        }
+    };
+}
+
+// Implements scalar * x for each of the component types, since a generic impl for any T
+// isn't allowed by the orphan rules.
+macro_rules! impl_scalar_mul {
+    ($ty:ident: $($t:ident),*) => {
+        $(
+            impl<const N: usize> ::std::ops::Mul<$ty<$t, N>> for $t {
+                type Output = $ty<$t, N>;
+
+                fn mul(self, other: $ty<$t, N>) -> Self::Output {
+                    other * self
+                }
+            }
+
+            impl<const N: usize> ::std::ops::Mul<&$ty<$t, N>> for $t {
+                type Output = $ty<$t, N>;
+
+                fn mul(self, other: &$ty<$t, N>) -> Self::Output {
+                    *other * self
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use forward_ref_binop;
+pub(crate) use forward_ref_op_assign;
+pub(crate) use impl_scalar_mul;
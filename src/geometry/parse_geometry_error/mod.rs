@@ -0,0 +1,67 @@
+use std::fmt;
+use std::error::Error;
+use std::str::FromStr;
+use super::dimension_mismatch::{DimensionMismatch, components_from_vec};
+
+// Returned when parsing a point, vector or normal from a string such as "(1, 2, 3)".
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseGeometryError {
+    MissingParentheses,
+    InvalidComponent(String),
+    WrongDimension(DimensionMismatch),
+}
+
+impl fmt::Display for ParseGeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseGeometryError::MissingParentheses => write!(f, "expected the components to be wrapped in parentheses"),
+            ParseGeometryError::InvalidComponent(component) => write!(f, "invalid component: {:?}", component),
+            ParseGeometryError::WrongDimension(mismatch) => write!(f, "{}", mismatch),
+        }
+    }
+}
+
+impl Error for ParseGeometryError { }
+
+impl From<DimensionMismatch> for ParseGeometryError {
+    fn from(mismatch: DimensionMismatch) -> Self {
+        ParseGeometryError::WrongDimension(mismatch)
+    }
+}
+
+// Parses exactly N comma separated components in parentheses. This is the inverse of
+// formatting the types with Display.
+pub fn components_from_str<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], ParseGeometryError> {
+    let inner = s.trim()
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or(ParseGeometryError::MissingParentheses)?;
+
+    let components = inner.split(',')
+        .map(|component| {
+            let component = component.trim();
+            component.parse().map_err(|_| ParseGeometryError::InvalidComponent(component.to_string()))
+        })
+        .collect::<Result<Vec<T>, _>>()?;
+
+    Ok(components_from_vec(components)?)
+}
+
+// Writes the components comma separated in parentheses, e.g. "(1, 2, 3)". Formatting options
+// such as precision are applied to each component.
+pub fn fmt_components<T: fmt::Display>(components: &[T], f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "(")?;
+
+    for (i, component) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+
+        component.fmt(f)?;
+    }
+
+    write!(f, ")")
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,55 @@
+use super::*;
+
+type Subject = ParseGeometryError;
+
+mod components_from_str {
+    use super::*;
+
+    #[test]
+    fn it_parses_comma_separated_components_in_parentheses() {
+        let components: [i32; 3] = components_from_str("(1, 2, 3)").unwrap();
+
+        assert_eq!(components, [1, 2, 3]);
+    }
+
+    #[test]
+    fn it_ignores_whitespace_around_the_components() {
+        let components: [f64; 2] = components_from_str("  ( 1.5,-2 )\n").unwrap();
+
+        assert_eq!(components, [1.5, -2.0]);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_parentheses_are_missing() {
+        let result: Result<[i32; 3], _> = components_from_str("1, 2, 3)");
+
+        assert_eq!(result, Err(Subject::MissingParentheses));
+    }
+
+    #[test]
+    fn it_returns_an_error_if_a_component_cannot_be_parsed() {
+        let result: Result<[i32; 3], _> = components_from_str("(1, two, 3)");
+
+        assert_eq!(result, Err(Subject::InvalidComponent("two".to_string())));
+    }
+
+    #[test]
+    fn it_returns_an_error_if_there_are_the_wrong_number_of_components() {
+        let result: Result<[i32; 3], _> = components_from_str("(1, 2)");
+
+        assert_eq!(result, Err(Subject::WrongDimension(DimensionMismatch { expected: 3, actual: 2 })));
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn it_describes_the_problem() {
+        let mismatch = DimensionMismatch { expected: 3, actual: 2 };
+
+        assert_eq!(Subject::MissingParentheses.to_string(), "expected the components to be wrapped in parentheses");
+        assert_eq!(Subject::InvalidComponent("x".to_string()).to_string(), "invalid component: \"x\"");
+        assert_eq!(Subject::WrongDimension(mismatch).to_string(), "expected 3 components but got 2");
+    }
+}
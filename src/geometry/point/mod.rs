@@ -1,190 +1,346 @@
-use generic_array::{ArrayLength, GenericArray};
-use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Index, IndexMut};
 use super::vector::Vector;
-use std::cmp::{min, max};
+use super::normal::Normal;
+use super::ops::{forward_ref_binop, forward_ref_op_assign, impl_scalar_mul};
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::parse_geometry_error::{ParseGeometryError, components_from_str, fmt_components};
+use super::dimension_mismatch::{DimensionMismatch, components_from_vec, components_from_slice};
+use super::float::{Float, Scalar, CastFrom, partial_min, partial_max};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+
+#[cfg(feature = "serde")]
+use super::serde_components;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point<T, const N: usize> {
+    pub components: [T; N],
+}
 
-#[derive(Debug, Default, PartialEq)]
-pub struct Point<T, N: ArrayLength<T>> {
-    pub components: GenericArray<T, N>,
+// Arrays only implement Default up to a fixed size, so it can't be derived for any N.
+impl<T: Default, const N: usize> Default for Point<T, N> {
+    fn default() -> Self {
+        Self::generate(|_| T::default())
+    }
 }
 
-// Cloning GenericArray doesn't seem to work properly, so implement it manually:
-impl<T: Clone, N: ArrayLength<T>> Clone for Point<T, N> {
-    fn clone(&self) -> Self {
-        self.components.iter().cloned().into()
+impl<T, const N: usize> Point<T, N> {
+    // Builds the point from a function of each component's index.
+    pub fn generate<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self { components: std::array::from_fn(f) }
+    }
+
+    pub fn try_from_iter<I: IntoIterator<Item=T>>(iter: I) -> Result<Self, DimensionMismatch> {
+        Self::try_from(iter.into_iter().collect::<Vec<_>>())
     }
 }
 
-impl<T, N: ArrayLength<T>, I, X> From<I> for Point<T, N>
-    where I: IntoIterator<Item=T, IntoIter=X>,
-          X: ExactSizeIterator<Item=T>,
-{
-    fn from(iter: I) -> Self {
-        Self { components: GenericArray::from_exact_iter(iter).unwrap() }
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self { components }
     }
 }
 
-// NotEq is a marker trait that's auto-implemented on all 2-tuples...
-pub auto trait NotEq { }
+// Indexes the components by axis, e.g. point[0] is the x component.
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
 
-// ...except those with two values of the same type...
-impl<T> !NotEq for (T, T) { }
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.components[axis]
+    }
+}
 
-impl<T, U, N: ArrayLength<T> + ArrayLength<U>> From<&Point<U, N>> for Point<T, N>
-    where (T, U): NotEq, // ...which lets us add a trait bound that avoids a conflict
-          T: From<U>,    //    with the auto-implemented From<T> trait.
-          U: Copy,
-{
-    fn from(p: &Point<U, N>) -> Self {
-        p.components.iter().map(|&a| a.into()).into()
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.components[axis]
     }
 }
 
-impl<T: Add<Output=T> + Copy, N: ArrayLength<T>> Add<Self> for &Point<T, N> {
-    type Output = Point<T, N>;
+impl<T, const N: usize> TryFrom<Vec<T>> for Point<T, N> {
+    type Error = DimensionMismatch;
 
-    fn add(self, other: Self) -> Self::Output {
+    fn try_from(components: Vec<T>) -> Result<Self, Self::Error> {
+        Ok(Self { components: components_from_vec(components)? })
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for Point<T, N> {
+    type Error = DimensionMismatch;
+
+    fn try_from(components: &[T]) -> Result<Self, Self::Error> {
+        Ok(Self { components: components_from_slice(components)? })
+    }
+}
+
+// Converts the components to another type, e.g. a Point2i to a Point2f. The try_ version
+// returns None if a component can't be represented, e.g. a NaN float cast to an integer.
+impl<T: Copy, const N: usize> Point<T, N> {
+    pub fn cast<U: CastFrom<T>>(&self) -> Point<U, N> {
+        Point { components: self.components.map(U::cast_from) }
+    }
+
+    pub fn try_cast<U: CastFrom<T> + Copy>(&self) -> Option<Point<U, N>> {
+        let components = self.components.map(U::try_cast_from);
+
+        match components.iter().all(Option::is_some) {
+            true => Some(Point::generate(|i| components[i].unwrap())),
+            false => None,
+        }
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for Point<T, N> {
+    type Float = T::Float;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Float>) -> bool {
         self.components.iter()
             .zip(other.components.iter())
-            .map(|(&a, &b)| a + b)
-            .into()
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for Point<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_components(&self.components, f)
     }
 }
 
-impl<T: Add<Output=T> + Copy, N: ArrayLength<T>> Add<&Vector<T, N>> for &Point<T, N> {
+impl<T: FromStr, const N: usize> FromStr for Point<T, N> {
+    type Err = ParseGeometryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { components: components_from_str(s)? })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize, const N: usize> Serialize for Point<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_components::serialize(&self.components, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Point<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self { components: serde_components::deserialize(deserializer)? })
+    }
+}
+
+impl<T: Add<Output=T> + Copy, const N: usize> Add for Point<T, N> {
     type Output = Point<T, N>;
 
-    fn add(self, vector: &Vector<T, N>) -> Self::Output {
-        self.components.iter()
-            .zip(vector.components.iter())
-            .map(|(&a, &b)| a + b)
-            .into()
+    fn add(self, other: Self) -> Self::Output {
+        Point::generate(|i| self.components[i] + other.components[i])
     }
 }
 
-impl<T: AddAssign + Copy, N: ArrayLength<T>> AddAssign<&Vector<T, N>> for Point<T, N> {
-    fn add_assign(&mut self, vector: &Vector<T, N>) {
+forward_ref_binop!(Add, add, Point, Point);
+
+impl<T: Add<Output=T> + Copy, const N: usize> Add<Vector<T, N>> for Point<T, N> {
+    type Output = Point<T, N>;
+
+    fn add(self, vector: Vector<T, N>) -> Self::Output {
+        Point::generate(|i| self.components[i] + vector.components[i])
+    }
+}
+
+forward_ref_binop!(Add, add, Point, Vector);
+
+impl<T: AddAssign + Copy, const N: usize> AddAssign<Vector<T, N>> for Point<T, N> {
+    fn add_assign(&mut self, vector: Vector<T, N>) {
         self.components.iter_mut()
             .zip(vector.components.iter())
             .for_each(|(a, b)| *a += *b);
     }
 }
 
-impl<T: Sub<Output=T> + Copy, N: ArrayLength<T>> Sub<Self> for &Point<T, N> {
+forward_ref_op_assign!(AddAssign, add_assign, Point, Vector);
+
+impl<T: Sub<Output=T> + Copy, const N: usize> Sub for Point<T, N> {
     type Output = Vector<T, N>;
 
     fn sub(self, other: Self) -> Self::Output {
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(a, b)| *a - *b)
-            .into()
+        Vector::generate(|i| self.components[i] - other.components[i])
     }
 }
 
-impl<T: Sub<Output=T> + Copy, N: ArrayLength<T>> Sub<&Vector<T, N>> for &Point<T, N> {
+forward_ref_binop!(Sub, sub, Point, Point);
+
+impl<T: Sub<Output=T> + Copy, const N: usize> Sub<Vector<T, N>> for Point<T, N> {
     type Output = Point<T, N>;
 
-    fn sub(self, vector: &Vector<T, N>) -> Self::Output {
-        self.components.iter()
-            .zip(vector.components.iter())
-            .map(|(&a, &b)| a - b)
-            .into()
+    fn sub(self, vector: Vector<T, N>) -> Self::Output {
+        Point::generate(|i| self.components[i] - vector.components[i])
     }
 }
 
-impl<T: SubAssign + Copy, N: ArrayLength<T>> SubAssign<&Vector<T, N>> for Point<T, N> {
-    fn sub_assign(&mut self, vector: &Vector<T, N>) {
+forward_ref_binop!(Sub, sub, Point, Vector);
+
+impl<T: SubAssign + Copy, const N: usize> SubAssign<Vector<T, N>> for Point<T, N> {
+    fn sub_assign(&mut self, vector: Vector<T, N>) {
         self.components.iter_mut()
             .zip(vector.components.iter())
             .for_each(|(a, b)| *a -= *b);
     }
 }
 
-impl<T: Sub<Output=T> + Copy + Into<f64>, N: ArrayLength<T>> Point<T, N> {
-    pub fn distance(&self, other: &Self) -> f64 {
+forward_ref_op_assign!(SubAssign, sub_assign, Point, Vector);
+
+// Offsets a point along a normal, e.g. to move it off a surface.
+impl<T: Add<Output=T> + Copy, const N: usize> Add<Normal<T, N>> for Point<T, N> {
+    type Output = Point<T, N>;
+
+    fn add(self, normal: Normal<T, N>) -> Self::Output {
+        Point::generate(|i| self.components[i] + normal.components[i])
+    }
+}
+
+forward_ref_binop!(Add, add, Point, Normal);
+
+impl<T: Sub<Output=T> + Copy, const N: usize> Sub<Normal<T, N>> for Point<T, N> {
+    type Output = Point<T, N>;
+
+    fn sub(self, normal: Normal<T, N>) -> Self::Output {
+        Point::generate(|i| self.components[i] - normal.components[i])
+    }
+}
+
+forward_ref_binop!(Sub, sub, Point, Normal);
+
+impl<T: AddAssign + Copy, const N: usize> AddAssign<Normal<T, N>> for Point<T, N> {
+    fn add_assign(&mut self, normal: Normal<T, N>) {
+        self.components.iter_mut()
+            .zip(normal.components.iter())
+            .for_each(|(a, b)| *a += *b);
+    }
+}
+
+forward_ref_op_assign!(AddAssign, add_assign, Point, Normal);
+
+impl<T: SubAssign + Copy, const N: usize> SubAssign<Normal<T, N>> for Point<T, N> {
+    fn sub_assign(&mut self, normal: Normal<T, N>) {
+        self.components.iter_mut()
+            .zip(normal.components.iter())
+            .for_each(|(a, b)| *a -= *b);
+    }
+}
+
+forward_ref_op_assign!(SubAssign, sub_assign, Point, Normal);
+
+impl<T: Sub<Output=T> + Scalar, const N: usize> Point<T, N> {
+    pub fn distance(&self, other: &Self) -> T::Float {
         (self - other).length()
     }
 
-    pub fn distance_squared(&self, other: &Self) -> f64 {
+    pub fn distance_squared(&self, other: &Self) -> T::Float {
         (self - other).length_squared()
     }
 }
 
-impl<T: Mul<Output=T> + Copy, N: ArrayLength<T>> Mul<T> for &Point<T, N> {
+impl<T: Mul<Output=T> + Copy, const N: usize> Mul<T> for Point<T, N> {
     type Output = Point<T, N>;
 
     fn mul(self, scalar: T) -> Self::Output {
-        self.components.iter().map(|a| *a * scalar).into()
+        Point::generate(|i| self.components[i] * scalar)
     }
 }
 
-impl<T: MulAssign + Copy, N: ArrayLength<T>> MulAssign<T> for Point<T, N> {
+impl<T: Mul<Output=T> + Copy, const N: usize> Mul<T> for &Point<T, N> {
+    type Output = Point<T, N>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        *self * scalar
+    }
+}
+
+impl_scalar_mul!(Point: f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl<T: MulAssign + Copy, const N: usize> MulAssign<T> for Point<T, N> {
     fn mul_assign(&mut self, scalar: T) {
         self.components.iter_mut().for_each(|a| *a *= scalar);
     }
 }
 
-impl<T: Into<f64> + Copy, D: Into<f64>, N: ArrayLength<T> + ArrayLength<f64>> Div<D> for &Point<T, N> {
-    type Output = Point<f64, N>;
+// The component-wise product and quotient, e.g. for scaling each axis by a different amount.
+impl<T: Mul<Output=T> + Copy, const N: usize> Point<T, N> {
+    pub fn hadamard_product(&self, other: &Self) -> Self {
+        Point::generate(|i| self.components[i] * other.components[i])
+    }
+}
 
-    fn div(self, divisor: D) -> Self::Output {
-        let inverse = divisor.into().recip();
+impl<T: Div<Output=T> + Copy, const N: usize> Point<T, N> {
+    pub fn hadamard_quotient(&self, other: &Self) -> Self {
+        Point::generate(|i| self.components[i] / other.components[i])
+    }
+}
 
-        self.components.iter().map(|&a| a.into() * inverse).into()
+impl<T: Scalar, const N: usize> Div<T::Float> for Point<T, N> {
+    type Output = Point<T::Float, N>;
+
+    fn div(self, divisor: T::Float) -> Self::Output {
+        let inverse = divisor.recip();
+
+        Point::generate(|i| self.components[i].to_float() * inverse)
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T::Float> for &Point<T, N> {
+    type Output = Point<T::Float, N>;
+
+    fn div(self, divisor: T::Float) -> Self::Output {
+        *self / divisor
     }
 }
 
-impl<D: Into<f64> + Copy, N: ArrayLength<f64>> DivAssign<D> for Point<f64, N> {
-    fn div_assign(&mut self, divisor: D) {
-        let inverse = divisor.into().recip();
+impl<F: Float, const N: usize> DivAssign<F> for Point<F, N> {
+    fn div_assign(&mut self, divisor: F) {
+        let inverse = divisor.recip();
 
         self.components.iter_mut().for_each(|a| *a *= inverse);
     }
 }
 
-impl<T: Into<f64> + Copy, N: ArrayLength<T> + ArrayLength<f64>> Point<T, N> {
-    pub fn lerp(&self, other: &Self, time: f64) -> Point<f64, N> {
-        let remainder = 1.0 - time;
+impl<T: Scalar, const N: usize> Point<T, N> {
+    pub fn lerp(&self, other: &Self, time: T::Float) -> Point<T::Float, N> {
+        let remainder = T::Float::ONE - time;
 
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(&a, &b)| a.into() * remainder + b.into() * time)
-            .into()
+        Point::generate(|i| {
+            self.components[i].to_float() * remainder + other.components[i].to_float() * time
+        })
     }
 }
 
-impl<T: Ord + Copy, N: ArrayLength<T>> Point<T, N> {
+impl<T: PartialOrd + Copy, const N: usize> Point<T, N> {
     pub fn min(&self, other: &Self) -> Self {
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(&a, &b)| min(a, b))
-            .into()
+        Point::generate(|i| partial_min(self.components[i], other.components[i]))
     }
 
     pub fn max(&self, other: &Self) -> Self {
-        self.components.iter()
-            .zip(other.components.iter())
-            .map(|(&a, &b)| max(a, b))
-            .into()
+        Point::generate(|i| partial_max(self.components[i], other.components[i]))
     }
 }
 
-impl<N: ArrayLength<f64>> Point<f64, N> {
+impl<F: Float, const N: usize> Point<F, N> {
     pub fn abs(&self) -> Self {
-        self.components.iter().map(|&a| a.abs()).into()
+        Point::generate(|i| self.components[i].abs())
     }
 
     pub fn floor(&self) -> Self {
-        self.components.iter().map(|&a| a.floor()).into()
+        Point::generate(|i| self.components[i].floor())
     }
 
     pub fn ceil(&self) -> Self {
-        self.components.iter().map(|&a| a.ceil()).into()
+        Point::generate(|i| self.components[i].ceil())
     }
 }
 
-impl<N: ArrayLength<i32>> Point<i32, N> {
+impl<const N: usize> Point<i32, N> {
     pub fn abs(&self) -> Self {
-        self.components.iter().map(|&a| a.abs()).into()
+        Point::generate(|i| self.components[i].abs())
     }
 }
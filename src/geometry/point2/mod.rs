@@ -1,12 +1,12 @@
-use generic_array::{arr, arr_impl, typenum::U2, sequence::Shorten};
 use super::point::*;
+use super::float::Real;
 use super::point3::Point3;
 
-pub type Point2<T> = Point<T, U2>;
+pub type Point2<T> = Point<T, 2>;
 
 impl<T: Copy> Point2<T> {
     pub fn new(x: T, y: T) -> Self {
-        Self { components: arr![T; x, y] }
+        Self { components: [x, y] }
     }
 
     pub fn x(&self) -> T {
@@ -22,15 +22,13 @@ impl<T: Copy> Point2<T> {
     }
 }
 
-impl<T> From<Point3<T>> for Point2<T> {
+impl<T: Copy> From<Point3<T>> for Point2<T> {
     fn from(p: Point3<T>) -> Self {
-        let (components, _) = p.components.pop_back();
-
-        Self { components }
+        Self::new(p.x(), p.y())
     }
 }
 
-pub type Point2f = Point2<f64>;
+pub type Point2f = Point2<Real>;
 pub type Point2i = Point2<i32>;
 
 #[cfg(test)]
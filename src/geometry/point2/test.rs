@@ -1,5 +1,7 @@
-use assert_approx_eq::assert_approx_eq;
 use super::*;
+use crate::assert_approx;
+use std::convert::TryFrom;
+use crate::geometry::dimension_mismatch::DimensionMismatch;
 use crate::geometry::vector2::Vector2;
 
 type Subject<T> = Point2<T>;
@@ -21,12 +23,12 @@ mod aliases {
 
     #[test]
     fn it_has_a_type_alias_for_a_point_of_signed_integers() {
-        Point2i::new(-1 as i32, 2);
+        Point2i::new(-1_i32, 2);
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_point_of_double_precision_floats() {
-        Point2f::new(0.1 as f64, 0.2);
+    fn it_has_a_type_alias_for_a_point_of_reals() {
+        Point2f::new(0.1 as Real, 0.2);
     }
 }
 
@@ -42,15 +44,17 @@ mod default {
     }
 }
 
-mod clone {
+mod copy {
     use super::*;
 
     #[test]
-    fn it_clones_the_point() {
-        let subject = Subject::new(1, 2).clone();
+    fn it_copies_the_point() {
+        let original = Subject::new(1, 2);
+        let subject = original;
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
+        assert_eq!(original.x(), 1);
     }
 }
 
@@ -58,13 +62,43 @@ mod conversions {
     use super::*;
 
     #[test]
-    fn it_can_build_a_point2_from_an_iterator() {
-        let subject: Subject<_> = (1..3).into();
+    fn it_can_build_a_point2_from_an_array() {
+        let subject: Subject<_> = [1, 2].into();
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
     }
 
+    #[test]
+    fn it_can_try_to_build_a_point2_from_a_slice() {
+        let subject = Subject::try_from(&[1, 2][..]).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result = Subject::try_from(&[1][..]);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn it_can_try_to_build_a_point2_from_an_iterator() {
+        let subject = Subject::try_from_iter(1..3).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_iterator_has_the_wrong_length() {
+        let result = Subject::<i32>::try_from_iter(1..10);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 2, actual: 9 }));
+    }
+
     #[test]
     fn it_can_build_a_point2_from_a_point3_by_dropping_the_z_component() {
         let subject: Subject<_> = Point3::new(1, 2, 3).into();
@@ -73,13 +107,48 @@ mod conversions {
         assert_eq!(subject.y(), 2);
     }
 
+}
+
+mod cast {
+    use super::*;
+
     #[test]
-    fn it_can_build_a_point2_from_a_point2_with_different_component_types() {
-        let point = &Point2i::new(1, 2);
-        let subject: Point2f = point.into();
+    fn it_converts_the_components_to_another_type() {
+        let subject: Point2f = Point2i::new(1, 2).cast();
 
-        assert_eq!(subject.x(), 1.0);
-        assert_eq!(subject.y(), 2.0);
+        assert_eq!(subject, Point2f::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn it_truncates_floats_when_casting_to_integers() {
+        let subject = Point2f::new(1.7, -2.7).cast::<i32>();
+
+        assert_eq!(subject, Point2i::new(1, -2));
+    }
+}
+
+mod try_cast {
+    use super::*;
+
+    #[test]
+    fn it_converts_float_coordinates_to_pixel_coordinates() {
+        let subject = Point2f::new(3.5, 4.5).floor().try_cast::<i32>();
+
+        assert_eq!(subject, Some(Point2i::new(3, 4)));
+    }
+
+    #[test]
+    fn it_returns_none_if_a_component_is_nan() {
+        let subject = Point2f::new(1.0, Real::NAN).try_cast::<i32>();
+
+        assert_eq!(subject, None);
+    }
+
+    #[test]
+    fn it_returns_none_if_a_component_is_out_of_range() {
+        let subject = Point2f::new(1e10, 1.0).try_cast::<i32>();
+
+        assert_eq!(subject, None);
     }
 }
 
@@ -91,7 +160,7 @@ mod addition {
         let a = Subject::new(1, 2);
         let b = Subject::new(4, 5);
 
-        let subject: Subject<_> = &a + &b;
+        let subject: Subject<_> = a + b;
 
         assert_eq!(subject.x(), 5);
         assert_eq!(subject.y(), 7);
@@ -102,7 +171,7 @@ mod addition {
         let point = Subject::new(1, 2);
         let vector = Vector2::new(3, 4);
 
-        let subject: Subject<_> = &point + &vector;
+        let subject: Subject<_> = point + vector;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 6);
@@ -129,7 +198,7 @@ mod subtraction {
         let a = Subject::new(5, 5);
         let b = Subject::new(1, 2);
 
-        let vector: Vector2<_> = &a - &b;
+        let vector: Vector2<_> = a - b;
 
         assert_eq!(vector.x(), 4);
         assert_eq!(vector.y(), 3);
@@ -140,7 +209,7 @@ mod subtraction {
         let point = Subject::new(5, 5);
         let vector = Vector2::new(1, 2);
 
-        let subject: Subject<_> = &point - &vector;
+        let subject: Subject<_> = point - vector;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 3);
@@ -167,7 +236,7 @@ mod distance {
         let a = Subject::new(1, 2);
         let b = Subject::new(5, 5);
 
-        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(a.distance(&b), 5.0 as Real);
     }
 }
 
@@ -179,7 +248,7 @@ mod distance_squared {
         let a = Subject::new(1, 2);
         let b = Subject::new(5, 5);
 
-        assert_eq!(a.distance_squared(&b), 25.0);
+        assert_eq!(a.distance_squared(&b), 25.0 as Real);
     }
 }
 
@@ -210,20 +279,20 @@ mod division {
 
     #[test]
     fn it_divides_by_a_divisor() {
-        let subject: Subject<_> = &Subject::new(1, 2) / 10;
+        let subject = &Subject::<i32>::new(1, 2) / 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
     }
 
     #[test]
     fn it_can_mutate_the_point() {
-        let mut subject = Subject::new(1.0, 2.0);
+        let mut subject = Subject::<f64>::new(1.0, 2.0);
 
-        subject /= 10;
+        subject /= 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
     }
 }
 
@@ -235,10 +304,10 @@ mod lerp {
         let a = Subject::new(1, 2);
         let b = Subject::new(5, 6);
 
-        let subject = a.lerp(&b, 0.5);
+        let subject = a.lerp(&b, 0.5 as Real);
 
-        assert_eq!(subject.x(), 3.0);
-        assert_eq!(subject.y(), 4.0);
+        assert_eq!(subject.x(), 3.0 as Real);
+        assert_eq!(subject.y(), 4.0 as Real);
     }
 }
 
@@ -1,11 +1,11 @@
-use generic_array::{arr, arr_impl, typenum::U3};
 use super::point::*;
+use super::float::Real;
 
-pub type Point3<T> = Point<T, U3>;
+pub type Point3<T> = Point<T, 3>;
 
 impl<T: Copy> Point3<T> {
     pub fn new(x: T, y: T, z: T) -> Self {
-        Self { components: arr![T; x, y, z] }
+        Self { components: [x, y, z] }
     }
 
     pub fn x(&self) -> T {
@@ -27,7 +27,7 @@ impl<T: Copy> Point3<T> {
     }
 }
 
-pub type Point3f = Point3<f64>;
+pub type Point3f = Point3<Real>;
 pub type Point3i = Point3<i32>;
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
-use assert_approx_eq::assert_approx_eq;
 use super::*;
+use crate::assert_approx;
+use std::convert::TryFrom;
+use crate::geometry::dimension_mismatch::DimensionMismatch;
 use crate::geometry::vector3::Vector3;
 
 type Subject<T> = Point3<T>;
@@ -22,12 +24,12 @@ mod aliases {
 
     #[test]
     fn it_has_a_type_alias_for_a_point_of_signed_integers() {
-        Point3i::new(-1 as i32, 2, -3);
+        Point3i::new(-1_i32, 2, -3);
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_point_of_double_precision_floats() {
-        Point3f::new(0.1 as f64, 0.2, 0.3);
+    fn it_has_a_type_alias_for_a_point_of_reals() {
+        Point3f::new(0.1 as Real, 0.2, 0.3);
     }
 }
 
@@ -44,16 +46,18 @@ mod default {
     }
 }
 
-mod clone {
+mod copy {
     use super::*;
 
     #[test]
-    fn it_clones_the_point() {
-        let subject = Subject::new(1, 2, 3).clone();
+    fn it_copies_the_point() {
+        let original = Subject::new(1, 2, 3);
+        let subject = original;
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
+        assert_eq!(original.x(), 1);
     }
 }
 
@@ -61,8 +65,8 @@ mod conversions {
     use super::*;
 
     #[test]
-    fn it_can_build_a_point3_from_an_iterator() {
-        let subject: Subject<_> = (1..4).into();
+    fn it_can_build_a_point3_from_an_array() {
+        let subject: Subject<_> = [1, 2, 3].into();
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
@@ -70,13 +74,53 @@ mod conversions {
     }
 
     #[test]
-    fn it_can_build_a_point3_from_a_point3_with_different_component_types() {
-        let point = &Point3i::new(1, 2, 3);
-        let subject: Point3f = point.into();
+    fn it_can_try_to_build_a_point3_from_a_slice() {
+        let subject = Subject::try_from(&[1, 2, 3][..]).unwrap();
 
-        assert_eq!(subject.x(), 1.0);
-        assert_eq!(subject.y(), 2.0);
-        assert_eq!(subject.z(), 3.0);
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result = Subject::try_from(&[1, 2][..]);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn it_can_try_to_build_a_point3_from_an_iterator() {
+        let subject = Subject::try_from_iter(1..4).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_iterator_has_the_wrong_length() {
+        let result = Subject::<i32>::try_from_iter(1..10);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 3, actual: 9 }));
+    }
+
+}
+
+mod cast {
+    use super::*;
+
+    #[test]
+    fn it_converts_the_components_to_another_type() {
+        let subject: Point3f = Point3i::new(1, 2, 3).cast();
+
+        assert_eq!(subject, Point3f::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn it_returns_none_if_a_component_cannot_be_represented() {
+        assert_eq!(Point3i::new(1, -2, 3).try_cast::<u8>(), None);
+        assert_eq!(Point3i::new(1, 2, 3).try_cast::<u8>(), Some(Point3::new(1, 2, 3)));
     }
 }
 
@@ -88,7 +132,7 @@ mod addition {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(4, 5, 6);
 
-        let subject: Subject<_> = &a + &b;
+        let subject: Subject<_> = a + b;
 
         assert_eq!(subject.x(), 5);
         assert_eq!(subject.y(), 7);
@@ -100,7 +144,7 @@ mod addition {
         let point = Subject::new(1, 2, 3);
         let vector = Vector3::new(4, 5, 6);
 
-        let subject: Subject<_> = &point + &vector;
+        let subject: Subject<_> = point + vector;
 
         assert_eq!(subject.x(), 5);
         assert_eq!(subject.y(), 7);
@@ -129,7 +173,7 @@ mod subtraction {
         let a = Subject::new(5, 5, 5);
         let b = Subject::new(1, 2, 3);
 
-        let vector: Vector3<_> = &a - &b;
+        let vector: Vector3<_> = a - b;
 
         assert_eq!(vector.x(), 4);
         assert_eq!(vector.y(), 3);
@@ -141,7 +185,7 @@ mod subtraction {
         let point = Subject::new(5, 5, 5);
         let vector = Vector3::new(1, 2, 3);
 
-        let subject: Subject<_> = &point - &vector;
+        let subject: Subject<_> = point - vector;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 3);
@@ -170,7 +214,7 @@ mod distance {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(5, 5, 5);
 
-        assert_eq!(a.distance(&b), f64::sqrt(29.0));
+        assert_eq!(a.distance(&b), Real::sqrt(29.0));
     }
 }
 
@@ -182,7 +226,7 @@ mod distance_squared {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(5, 5, 5);
 
-        assert_eq!(a.distance_squared(&b), 29.0);
+        assert_eq!(a.distance_squared(&b), 29.0 as Real);
     }
 }
 
@@ -215,22 +259,22 @@ mod division {
 
     #[test]
     fn it_divides_by_a_divisor() {
-        let subject: Subject<_> = &Subject::new(1, 2, 3) / 10;
+        let subject = &Subject::<i32>::new(1, 2, 3) / 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 
     #[test]
     fn it_can_mutate_the_point() {
-        let mut subject = Subject::new(1.0, 2.0, 3.0);
+        let mut subject = Subject::<f64>::new(1.0, 2.0, 3.0);
 
-        subject /= 10;
+        subject /= 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 }
 
@@ -242,11 +286,11 @@ mod lerp {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(5, 6, 7);
 
-        let subject = a.lerp(&b, 0.5);
+        let subject = a.lerp(&b, 0.5 as Real);
 
-        assert_eq!(subject.x(), 3.0);
-        assert_eq!(subject.y(), 4.0);
-        assert_eq!(subject.z(), 5.0);
+        assert_eq!(subject.x(), 3.0 as Real);
+        assert_eq!(subject.y(), 4.0 as Real);
+        assert_eq!(subject.z(), 5.0 as Real);
     }
 }
 
@@ -264,6 +308,14 @@ mod min {
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
+
+    #[test]
+    fn it_works_for_floats_and_ignores_nans() {
+        let a = Point3f::new(1.0, Real::NAN, 3.0);
+        let b = Point3f::new(2.0, 2.0, -3.0);
+
+        assert_eq!(a.min(&b), Point3f::new(1.0, 2.0, -3.0));
+    }
 }
 
 mod max {
@@ -280,6 +332,14 @@ mod max {
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
+
+    #[test]
+    fn it_works_for_floats_and_ignores_nans() {
+        let a = Point3f::new(1.0, 2.0, 3.0);
+        let b = Point3f::new(2.0, Real::NAN, -3.0);
+
+        assert_eq!(a.max(&b), Point3f::new(2.0, 2.0, 3.0));
+    }
 }
 
 mod abs {
@@ -354,3 +414,165 @@ mod permute {
         assert_eq!(permute_120.z(), 5);
     }
 }
+
+mod approx_eq {
+    use super::*;
+    use crate::geometry::approx_eq::ApproxEq;
+
+    #[test]
+    fn it_compares_each_component() {
+        let subject = Point3f::new(1.0, 2.0, 3.0);
+
+        assert!(subject.abs_diff_eq(&Point3f::new(1.0, 2.05, 3.0), 0.1));
+        assert!(!subject.abs_diff_eq(&Point3f::new(1.0, 2.0, 3.2), 0.1));
+    }
+
+    #[test]
+    fn it_can_be_asserted() {
+        assert_approx!(Point3f::new(0.1 + 0.2, 1.0, 1.0), Point3f::new(0.3, 1.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "right: `Point { components: [1.0, 2.0, 4.0] }`")]
+    fn it_prints_both_points_if_they_are_not_equal() {
+        assert_approx!(Point3f::new(1.0, 2.0, 3.0), Point3f::new(1.0, 2.0, 4.0), max_relative = 0.01);
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn it_formats_the_components_in_parentheses() {
+        assert_eq!(Subject::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn it_applies_the_precision_to_each_component() {
+        assert_eq!(format!("{:.2}", Subject::new(1.0, 0.5, 1.0 / 3.0)), "(1.00, 0.50, 0.33)");
+    }
+}
+
+mod from_str {
+    use super::*;
+    use crate::geometry::parse_geometry_error::ParseGeometryError;
+
+    #[test]
+    fn it_parses_the_displayed_format() {
+        let subject: Point3f = "(1.5, -2, 3e2)".parse().unwrap();
+
+        assert_eq!(subject, Point3f::new(1.5, -2.0, 300.0));
+    }
+
+    #[test]
+    fn it_round_trips_through_display() {
+        let subject = Point3f::new(0.1, 1.0 / 3.0, -7.25);
+
+        assert_eq!(subject.to_string().parse::<Point3f>(), Ok(subject));
+    }
+
+    #[test]
+    fn it_returns_an_error_for_the_wrong_number_of_components() {
+        let result = "(1, 2)".parse::<Point3i>();
+
+        assert_eq!(result, Err(ParseGeometryError::WrongDimension(DimensionMismatch { expected: 3, actual: 2 })));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_an_array_of_components() {
+        let json = serde_json::to_string(&Point3f::new(1.0, 2.5, -3.0)).unwrap();
+
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+    }
+
+    #[test]
+    fn it_deserializes_from_an_array_of_components() {
+        let subject: Point3i = serde_json::from_str("[1, 2, 3]").unwrap();
+
+        assert_eq!(subject, Point3i::new(1, 2, 3));
+    }
+
+    #[test]
+    fn it_returns_an_error_if_there_are_the_wrong_number_of_components() {
+        let error = serde_json::from_str::<Point3i>("[1, 2]").unwrap_err();
+
+        assert!(error.to_string().starts_with("invalid length 2, expected an array of 3 components"));
+        assert!(serde_json::from_str::<Point3i>("[1, 2, 3, 4]").is_err());
+    }
+}
+
+mod normal_offset {
+    use super::*;
+    use crate::geometry::normal3::Normal3;
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_offsets_the_point_along_a_normal() {
+        let point = Subject::new(1.0, 2.0, 3.0);
+        let normal = Normal3::new(0.0, 0.0, 1.0);
+
+        assert_eq!(point + normal * 0.5, Subject::new(1.0, 2.0, 3.5));
+        assert_eq!(&point - normal, Subject::new(1.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn it_can_mutate_the_point() {
+        let mut subject = Subject::new(1, 2, 3);
+
+        subject += Normal3::new(1, 0, 0);
+        subject -= &Normal3::new(0, 1, 0);
+
+        assert_eq!(subject, Subject::new(2, 1, 3));
+    }
+}
+
+mod mixed_operands {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_can_mix_owned_and_borrowed_operands() {
+        let p = Subject::new(1, 2, 3);
+        let v = Vector3::new(1, 1, 1);
+
+        assert_eq!(&p + v, p + v);
+        assert_eq!(p - &v, p - v);
+        assert_eq!(&p - &p, Vector3::new(0, 0, 0));
+    }
+
+    #[test]
+    fn it_multiplies_a_scalar_by_a_point() {
+        assert_eq!(2.0 * Point3f::new(1.0, 2.0, 3.0), Point3f::new(2.0, 4.0, 6.0));
+    }
+}
+
+mod index {
+    use super::*;
+
+    #[test]
+    fn it_indexes_the_components_by_axis() {
+        let mut subject = Subject::new(1, 2, 3);
+        subject[1] += 5;
+
+        assert_eq!(subject[1], 7);
+        assert_eq!(subject.components, [1, 7, 3]);
+    }
+}
+
+mod hadamard {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_and_divides_the_components_pairwise() {
+        let subject = Subject::new(2, 4, 6);
+        let scale = Subject::new(2, 2, 3);
+
+        assert_eq!(subject.hadamard_product(&scale), Subject::new(4, 8, 18));
+        assert_eq!(subject.hadamard_quotient(&scale), Subject::new(1, 2, 2));
+    }
+}
@@ -0,0 +1,228 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, Div, Neg};
+use super::vector3::Vector3f;
+use super::matrix4x4::Matrix4x4;
+use super::transform::Transform;
+use super::float::Real;
+use super::approx_eq::{ApproxEq, Tolerance};
+
+// A unit quaternion represents a rotation. The imaginary part is stored in a vector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub v: Vector3f,
+    pub w: Real,
+}
+
+impl Quaternion {
+    pub fn new(v: Vector3f, w: Real) -> Self {
+        Self { v, w }
+    }
+
+    pub fn dot(&self, other: &Self) -> Real {
+        self.v.dot(other.v) + self.w * other.w
+    }
+
+    pub fn normalize(&self) -> Self {
+        *self / self.dot(self).sqrt()
+    }
+
+    // Spherical linear interpolation moves at a constant angular speed between the rotations.
+    // Falls back to linear interpolation when they're nearly parallel to avoid dividing by zero.
+    pub fn slerp(&self, other: &Self, t: Real) -> Self {
+        let cos_theta = self.dot(other);
+
+        if cos_theta > 0.9995 {
+            return (*self * (1.0 - t) + *other * t).normalize();
+        }
+
+        let theta = cos_theta.clamp(-1.0, 1.0).acos();
+        let theta_p = theta * t;
+        let q_perp = (*other - *self * cos_theta).normalize();
+
+        *self * theta_p.cos() + q_perp * theta_p.sin()
+    }
+
+    pub fn to_transform(&self) -> Transform {
+        let (x, y, z, w) = (self.v.x(), self.v.y(), self.v.z(), self.w);
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (x * w, y * w, z * w);
+
+        let m = Matrix4x4::new([
+            [1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy), 0.0],
+            [2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx), 0.0],
+            [2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        // The matrix above is the inverse rotation. It's orthogonal so the transpose is the rotation.
+        Transform::from_matrices(m.transpose(), m)
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::new(Vector3f::new(0.0, 0.0, 0.0), 1.0)
+    }
+}
+
+impl ApproxEq for Quaternion {
+    type Float = Real;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Real>) -> bool {
+        self.v.approx_eq(&other.v, tolerance) && self.w.approx_eq(&other.w, tolerance)
+    }
+}
+
+// Only the upper-left 3x3 rotation part of the transform is considered.
+impl From<&Transform> for Quaternion {
+    fn from(transform: &Transform) -> Self {
+        let m = &transform.m.m;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        if trace > 0.0 {
+            let s = (trace + 1.0).sqrt();
+            let w = s / 2.0;
+            let s = 0.5 / s;
+
+            let v = Vector3f::new(
+                (m[2][1] - m[1][2]) * s,
+                (m[0][2] - m[2][0]) * s,
+                (m[1][0] - m[0][1]) * s,
+            );
+
+            return Self::new(v, w);
+        }
+
+        // Compute the largest of x, y and z first for numerical stability.
+        let next = [1, 2, 0];
+        let mut i = if m[1][1] > m[0][0] { 1 } else { 0 };
+        if m[2][2] > m[i][i] { i = 2; }
+
+        let j = next[i];
+        let k = next[j];
+
+        let mut s = ((m[i][i] - (m[j][j] + m[k][k])) + 1.0).sqrt();
+        let mut q = [0.0; 3];
+
+        q[i] = s * 0.5;
+        if s != 0.0 { s = 0.5 / s; }
+
+        let w = (m[k][j] - m[j][k]) * s;
+        q[j] = (m[j][i] + m[i][j]) * s;
+        q[k] = (m[k][i] + m[i][k]) * s;
+
+        Self::new(Vector3f::new(q[0], q[1], q[2]), w)
+    }
+}
+
+// The arithmetic is implemented on owned values. Quaternions are Copy, so the borrowed
+// forms just forward to it.
+impl Add<Self> for Quaternion {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.v + other.v, self.w + other.w)
+    }
+}
+
+impl Add<Self> for &Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, other: Self) -> Self::Output {
+        *self + *other
+    }
+}
+
+impl AddAssign<Self> for Quaternion {
+    fn add_assign(&mut self, other: Self) {
+        self.v += other.v;
+        self.w += other.w;
+    }
+}
+
+impl AddAssign<&Self> for Quaternion {
+    fn add_assign(&mut self, other: &Self) {
+        *self += *other;
+    }
+}
+
+impl Sub<Self> for Quaternion {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.v - other.v, self.w - other.w)
+    }
+}
+
+impl Sub<Self> for &Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, other: Self) -> Self::Output {
+        *self - *other
+    }
+}
+
+impl SubAssign<Self> for Quaternion {
+    fn sub_assign(&mut self, other: Self) {
+        self.v -= other.v;
+        self.w -= other.w;
+    }
+}
+
+impl SubAssign<&Self> for Quaternion {
+    fn sub_assign(&mut self, other: &Self) {
+        *self -= *other;
+    }
+}
+
+impl Mul<Real> for Quaternion {
+    type Output = Self;
+
+    fn mul(self, scalar: Real) -> Self::Output {
+        Self::new(self.v * scalar, self.w * scalar)
+    }
+}
+
+impl Mul<Real> for &Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, scalar: Real) -> Self::Output {
+        *self * scalar
+    }
+}
+
+impl Div<Real> for Quaternion {
+    type Output = Self;
+
+    fn div(self, divisor: Real) -> Self::Output {
+        Self::new(self.v / divisor, self.w / divisor)
+    }
+}
+
+impl Div<Real> for &Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, divisor: Real) -> Self::Output {
+        *self / divisor
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.v, -self.w)
+    }
+}
+
+impl Neg for &Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,200 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = Quaternion;
+
+// The quaternion for a rotation of theta degrees about the z axis.
+fn about_z(degrees: Real) -> Subject {
+    let (sin, cos) = (degrees.to_radians() / 2.0).sin_cos();
+
+    Subject::new(Vector3f::new(0.0, 0.0, sin), cos)
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_quaternion_from_an_imaginary_vector_and_a_real_part() {
+        let subject = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+
+        assert_eq!(subject.v, Vector3f::new(1.0, 2.0, 3.0));
+        assert_eq!(subject.w, 4.0);
+    }
+}
+
+mod default {
+    use super::*;
+
+    #[test]
+    fn it_builds_the_identity_rotation() {
+        let subject = Subject::default();
+
+        assert_eq!(subject.v, Vector3f::new(0.0, 0.0, 0.0));
+        assert_eq!(subject.w, 1.0);
+        assert!(subject.to_transform().is_identity());
+    }
+}
+
+mod arithmetic {
+    use super::*;
+
+    #[test]
+    fn it_adds_and_subtracts_quaternions() {
+        let a = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+        let b = Subject::new(Vector3f::new(1.0, 1.0, 1.0), 1.0);
+
+        assert_eq!(a + b, Subject::new(Vector3f::new(2.0, 3.0, 4.0), 5.0));
+        assert_eq!(a - b, Subject::new(Vector3f::new(0.0, 1.0, 2.0), 3.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_accepts_borrowed_operands() {
+        let a = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+        let b = Subject::new(Vector3f::new(1.0, 1.0, 1.0), 1.0);
+
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - &b, a - b);
+        assert_eq!(&a * 2.0, a * 2.0);
+        assert_eq!(&a / 2.0, a / 2.0);
+        assert_eq!(-&a, -a);
+    }
+
+    #[test]
+    fn it_can_mutate_the_quaternion() {
+        let mut subject = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+        let other = Subject::new(Vector3f::new(1.0, 1.0, 1.0), 1.0);
+
+        subject += other;
+        assert_eq!(subject, Subject::new(Vector3f::new(2.0, 3.0, 4.0), 5.0));
+
+        subject -= &other;
+        assert_eq!(subject, Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0));
+    }
+
+    #[test]
+    fn it_scales_and_divides_by_a_scalar() {
+        let subject = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+
+        assert_eq!(subject * 2.0, Subject::new(Vector3f::new(2.0, 4.0, 6.0), 8.0));
+        assert_eq!(subject / 2.0, Subject::new(Vector3f::new(0.5, 1.0, 1.5), 2.0));
+    }
+
+    #[test]
+    fn it_negates_the_quaternion() {
+        let subject = Subject::new(Vector3f::new(1.0, -2.0, 3.0), 4.0);
+
+        assert_eq!(-subject, Subject::new(Vector3f::new(-1.0, 2.0, -3.0), -4.0));
+    }
+}
+
+mod dot {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_four_dimensional_dot_product() {
+        let a = Subject::new(Vector3f::new(1.0, 2.0, 3.0), 4.0);
+        let b = Subject::new(Vector3f::new(5.0, 6.0, 7.0), 8.0);
+
+        assert_eq!(a.dot(&b), 70.0);
+    }
+}
+
+mod normalize {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_quaternion_of_unit_length() {
+        let subject = Subject::new(Vector3f::new(1.0, 1.0, 1.0), 1.0).normalize();
+
+        assert_approx!(subject, Subject::new(Vector3f::new(0.5, 0.5, 0.5), 0.5), epsilon = 1e-6);
+    }
+}
+
+mod slerp {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_end_points_at_zero_and_one() {
+        let a = about_z(0.0);
+        let b = about_z(90.0);
+
+        assert_approx!(a.slerp(&b, 0.0), a, epsilon = 1e-6);
+        assert_approx!(a.slerp(&b, 1.0), b, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_interpolates_the_angle_at_a_constant_rate() {
+        let a = about_z(0.0);
+        let b = about_z(120.0);
+
+        assert_approx!(a.slerp(&b, 0.25), about_z(30.0), epsilon = 1e-6);
+        assert_approx!(a.slerp(&b, 0.5), about_z(60.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_interpolates_nearly_parallel_quaternions() {
+        let a = about_z(10.0);
+        let b = about_z(10.1);
+
+        let subject = a.slerp(&b, 0.5);
+
+        assert_approx!(subject, about_z(10.05), epsilon = 1e-6);
+        assert_approx!(subject.dot(&subject), 1.0, epsilon = 1e-6);
+    }
+}
+
+mod to_transform {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_equivalent_rotation_transform() {
+        let subject = about_z(90.0).to_transform();
+
+        assert_approx!(subject.m, Transform::rotate_z(90.0).m, epsilon = 1e-6);
+        assert_approx!(subject.m_inv, Transform::rotate_z(90.0).m_inv, epsilon = 1e-6);
+    }
+}
+
+mod conversions {
+    use super::*;
+
+    #[test]
+    fn it_can_build_a_quaternion_from_a_rotation_transform() {
+        let transform = Transform::rotate_z(90.0);
+        let subject: Subject = (&transform).into();
+
+        assert_approx!(subject, about_z(90.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_round_trips_rotations_whose_trace_is_negative() {
+        let axes = [
+            Vector3f::new(1.0, 0.0, 0.0),
+            Vector3f::new(0.0, 1.0, 0.0),
+            Vector3f::new(0.0, 0.0, 1.0),
+            Vector3f::new(1.0, 2.0, 3.0),
+        ];
+
+        for axis in axes.iter() {
+            let transform = Transform::rotate(170.0, axis);
+            let subject = Subject::from(&transform);
+
+            assert_approx!(subject.to_transform().m, transform.m, epsilon = 1e-6);
+        }
+    }
+}
+
+mod approx_eq {
+    use super::*;
+    use crate::geometry::approx_eq::ApproxEq;
+
+    #[test]
+    fn it_compares_the_vector_and_scalar_parts() {
+        let subject = about_z(30.0);
+
+        assert!(subject.abs_diff_eq(&about_z(30.0 + 1e-9), 1e-6));
+        assert!(!subject.abs_diff_eq(&about_z(31.0), 1e-6));
+        assert!(!subject.abs_diff_eq(&Subject::new(subject.v, -subject.w), 1e-6));
+    }
+}
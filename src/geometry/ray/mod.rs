@@ -1,39 +1,99 @@
-use std::f64::INFINITY;
-use std::cell::RefCell;
 use super::point3::Point3f;
 use super::vector3::Vector3f;
+use super::normal3::Normal3f;
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::float::{Real, next_float_up, next_float_down};
 use crate::dummy::Medium;
 
-#[derive(Debug, Clone, PartialEq)]
+// Stops shadow rays just short of their target so they don't hit the surface they're aimed at.
+pub const SHADOW_EPSILON: Real = 0.0001;
+
+// Rays are plain values so they can be shared between threads. Shapes take a &Ray and return
+// the distance to the hit, so callers looking for the closest hit shorten t_max themselves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ray {
     pub o: Point3f,
     pub d: Vector3f,
-    pub t_max: RefCell<f64>,
-    pub time: f64,
+    #[cfg_attr(feature = "serde", serde(with = "serde_t_max"))]
+    pub t_max: Real,
+    pub time: Real,
     pub medium: Option<Medium>,
 }
 
 impl Ray {
-    pub fn new(o: Point3f, d: Vector3f, t_max: Option<f64>, time: Option<f64>, medium: Option<Medium>) -> Self {
-        let t_max = RefCell::new(t_max.unwrap_or(INFINITY));
+    pub fn new(o: Point3f, d: Vector3f, t_max: Option<Real>, time: Option<Real>, medium: Option<Medium>) -> Self {
+        let t_max = t_max.unwrap_or(Real::INFINITY);
         let time = time.unwrap_or(0.0);
 
         Self { o, d, t_max, time, medium }
     }
 
-    pub fn at(&self, time: f64) -> Point3f {
-        let origin = &self.o;
-        let offset = &self.d * time;
+    pub fn at(&self, time: Real) -> Point3f {
+        self.o + self.d * time
+    }
+
+    // Builds a ray leaving a surface in direction d. The origin is offset so the ray can't
+    // re-intersect the surface it started on because of floating point error in p.
+    pub fn spawn(p: &Point3f, p_error: &Vector3f, n: &Normal3f, d: &Vector3f, time: Real, medium: Option<Medium>) -> Self {
+        let o = offset_ray_origin(p, p_error, n, d);
+
+        Self::new(o, *d, None, Some(time), medium)
+    }
+
+    // Builds a ray leaving a surface towards a target point, e.g. for a shadow ray.
+    // The ray's t_max is set so it stops just before the target.
+    pub fn spawn_to(p: &Point3f, p_error: &Vector3f, n: &Normal3f, target: &Point3f, time: Real, medium: Option<Medium>) -> Self {
+        let o = offset_ray_origin(p, p_error, n, &(target - p));
+        let d = target - o;
 
-        origin + &offset
+        Self::new(o, d, Some(1.0 - SHADOW_EPSILON), Some(time), medium)
     }
 }
 
+// The medium isn't numeric so it has to be equal.
+impl ApproxEq for Ray {
+    type Float = Real;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Real>) -> bool {
+        self.o.approx_eq(&other.o, tolerance) &&
+        self.d.approx_eq(&other.d, tolerance) &&
+        self.t_max.approx_eq(&other.t_max, tolerance) &&
+        self.time.approx_eq(&other.time, tolerance) &&
+        self.medium == other.medium
+    }
+}
+
+// Pushes p along the normal, on the same side as w, far enough to leave its error box.
+// The result is then rounded away from the surface so it can't round back into the box.
+pub fn offset_ray_origin(p: &Point3f, p_error: &Vector3f, n: &Normal3f, w: &Vector3f) -> Point3f {
+    let distance = n.abs().dot(p_error);
+    let mut offset = Vector3f::from(n) * distance;
+
+    if w.dot(n) < 0.0 {
+        offset = -offset;
+    }
+
+    let mut po = p + offset;
+
+    po.components.iter_mut()
+        .zip(offset.components.iter())
+        .for_each(|(a, &b)| {
+            if b > 0.0 {
+                *a = next_float_up(*a);
+            } else if b < 0.0 {
+                *a = next_float_down(*a);
+            }
+        });
+
+    po
+}
+
 impl Default for Ray {
     fn default() -> Self {
         let o = Point3f::default();
         let d = Vector3f::default();
-        let t_max = Some(INFINITY);
+        let t_max = Some(Real::INFINITY);
         let time = Some(0.0);
         let medium = None;
 
@@ -41,5 +101,23 @@ impl Default for Ray {
     }
 }
 
+// Formats like JSON can't represent infinity, so an unbounded ray's t_max is stored as null.
+#[cfg(feature = "serde")]
+mod serde_t_max {
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use super::Real;
+
+    pub fn serialize<S: Serializer>(t_max: &Real, serializer: S) -> Result<S::Ok, S::Error> {
+        match *t_max == Real::INFINITY {
+            true => None,
+            false => Some(t_max),
+        }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Real, D::Error> {
+        Ok(Option::deserialize(deserializer)?.unwrap_or(Real::INFINITY))
+    }
+}
+
 #[cfg(test)]
 mod test;
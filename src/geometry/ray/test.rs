@@ -1,4 +1,5 @@
 use super::*;
+use crate::assert_approx;
 use crate::dummy::Medium;
 
 type Subject = Ray;
@@ -15,16 +16,16 @@ mod new {
         let medium = Some(Medium::new());
 
         let subject = Subject::new(
-            origin.clone(),
-            direction.clone(),
+            origin,
+            direction,
             t_max,
             time,
-            medium.clone(),
+            medium,
         );
 
         assert_eq!(subject.o, origin);
         assert_eq!(subject.d, direction);
-        assert_eq!(subject.t_max, RefCell::new(123.0));
+        assert_eq!(subject.t_max, 123.0);
         assert_eq!(subject.time, 456.0);
         assert_eq!(subject.medium, medium);
     }
@@ -39,22 +40,35 @@ mod default {
 
         assert_eq!(subject.o, Point3f::default());
         assert_eq!(subject.d, Vector3f::default());
-        assert_eq!(subject.t_max, RefCell::new(INFINITY));
+        assert_eq!(subject.t_max, Real::INFINITY);
         assert_eq!(subject.time, 0.0);
         assert_eq!(subject.medium, None);
     }
 }
 
-mod interior_mutability {
+mod threads {
     use super::*;
+    use std::thread;
+
+    fn assert_send_sync_copy<T: Send + Sync + Copy>() { }
 
     #[test]
-    fn it_allows_t_max_to_be_mutated_when_the_ray_is_immutable() {
+    fn it_can_be_shared_and_copied_between_threads() {
+        assert_send_sync_copy::<Subject>();
+    }
+
+    #[test]
+    fn it_can_be_moved_to_another_thread_and_shortened_there() {
         let subject = Subject::default();
 
-        *subject.t_max.borrow_mut() = 555.0;
+        let shortened = thread::spawn(move || {
+            let mut ray = subject;
+            ray.t_max = 555.0;
+            ray
+        }).join().unwrap();
 
-        assert_eq!(subject.t_max, RefCell::new(555.0));
+        assert_eq!(shortened.t_max, 555.0);
+        assert_eq!(subject.t_max, Real::INFINITY);
     }
 }
 
@@ -69,8 +83,169 @@ mod at {
         let subject = Subject::new(origin, direction, None, None, None);
         let point = subject.at(1.7);
 
-        assert_eq!(point.x(), 1.7);
-        assert_eq!(point.y(), 3.4);
-        assert_eq!(point.z(), 5.1);
+        assert_approx!(point, Point3f::new(1.7, 3.4, 5.1));
+    }
+}
+
+mod offset_ray_origin {
+    use super::*;
+
+    #[test]
+    fn it_moves_the_point_along_the_normal_by_the_projected_error() {
+        let p = Point3f::new(1.0, 2.0, 3.0);
+        let p_error = Vector3f::new(0.1, 0.2, 0.3);
+        let n = Normal3f::new(0.0, 0.0, 1.0);
+        let w = Vector3f::new(1.0, 0.0, 1.0);
+
+        let subject = offset_ray_origin(&p, &p_error, &n, &w);
+
+        assert_eq!(subject.x(), 1.0);
+        assert_eq!(subject.y(), 2.0);
+        assert!(subject.z() > 3.3);
+    }
+
+    #[test]
+    fn it_moves_the_point_to_the_side_of_the_surface_that_w_points_to() {
+        let p = Point3f::new(1.0, 2.0, 3.0);
+        let p_error = Vector3f::new(0.1, 0.2, 0.3);
+        let n = Normal3f::new(0.0, 0.0, 1.0);
+        let w = Vector3f::new(0.0, 0.0, -1.0);
+
+        let subject = offset_ray_origin(&p, &p_error, &n, &w);
+
+        assert!(subject.z() < 2.7);
+    }
+
+    #[test]
+    fn it_moves_the_point_outside_the_error_box() {
+        let p = Point3f::new(0.5, -0.25, 2.0);
+        let p_error = Vector3f::new(1e-9, 2e-9, 3e-9);
+        let n = Normal3f::new(1.0, 2.0, -2.0).normalize();
+        let w = Vector3f::new(1.0, 1.0, 0.0);
+
+        let subject = offset_ray_origin(&p, &p_error, &n, &w);
+        let offset = subject - p;
+
+        // The offset along the normal must be at least the error box's extent along the normal.
+        assert!(offset.dot(&n) >= n.abs_dot(&p_error));
+    }
+
+    #[test]
+    fn it_does_not_move_the_point_if_there_is_no_error() {
+        let p = Point3f::new(1.0, 2.0, 3.0);
+        let p_error = Vector3f::new(0.0, 0.0, 0.0);
+        let n = Normal3f::new(0.0, 1.0, 0.0);
+        let w = Vector3f::new(0.0, 1.0, 0.0);
+
+        assert_eq!(offset_ray_origin(&p, &p_error, &n, &w), p);
+    }
+}
+
+mod spawn {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_ray_whose_origin_is_offset_from_the_surface() {
+        let p = Point3f::new(0.0, 0.0, 0.0);
+        let p_error = Vector3f::new(1e-6, 1e-6, 1e-6);
+        let n = Normal3f::new(0.0, 1.0, 0.0);
+        let d = Vector3f::new(0.0, 1.0, 1.0);
+
+        let subject = Subject::spawn(&p, &p_error, &n, &d, 0.0, None);
+
+        assert!(subject.o.y() > 1e-6);
+        assert_eq!(subject.d, d);
+        assert_eq!(subject.t_max, Real::INFINITY);
+    }
+
+    #[test]
+    fn it_sets_the_time_and_medium_of_the_ray() {
+        let (p, p_error, n) = (Point3f::default(), Vector3f::default(), Normal3f::new(0.0, 1.0, 0.0));
+        let subject = Subject::spawn(&p, &p_error, &n, &Vector3f::new(0.0, 1.0, 0.0), 0.5, Some(Medium::new()));
+
+        assert_eq!(subject.time, 0.5);
+        assert_eq!(subject.medium, Some(Medium::new()));
+    }
+}
+
+mod spawn_to {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_ray_that_stops_just_before_the_target() {
+        let p = Point3f::new(0.0, 0.0, 0.0);
+        let p_error = Vector3f::new(1e-6, 1e-6, 1e-6);
+        let n = Normal3f::new(0.0, 0.0, 1.0);
+        let target = Point3f::new(0.0, 0.0, 10.0);
+
+        let subject = Subject::spawn_to(&p, &p_error, &n, &target, 0.0, None);
+        let end = subject.at(subject.t_max);
+
+        assert!(subject.o.z() > 1e-6);
+        assert!(end.z() < 10.0);
+        assert!(end.z() > 9.99);
+    }
+
+    #[test]
+    fn it_sets_the_time_and_medium_of_the_ray() {
+        let (p, p_error, n) = (Point3f::default(), Vector3f::default(), Normal3f::new(0.0, 0.0, 1.0));
+        let subject = Subject::spawn_to(&p, &p_error, &n, &Point3f::new(0.0, 0.0, 10.0), 0.5, Some(Medium::new()));
+
+        assert_eq!(subject.time, 0.5);
+        assert_eq!(subject.medium, Some(Medium::new()));
+    }
+}
+
+mod approx_eq {
+    use super::*;
+
+    #[test]
+    fn it_compares_the_origin_direction_t_max_and_time() {
+        let subject = Subject::new(Point3f::new(1.0, 2.0, 3.0), Vector3f::new(0.0, 0.0, 1.0), Some(10.0), None, None);
+
+        let mut other = subject;
+        other.o = Point3f::new(1.0, 2.0, 3.05);
+        other.t_max = 10.05;
+
+        assert_approx!(subject, other, epsilon = 0.1);
+
+        other.time = 1.0;
+
+        assert!(!subject.abs_diff_eq(&other, 0.1));
+    }
+
+    #[test]
+    fn it_treats_infinite_t_max_as_equal() {
+        assert_approx!(Subject::default(), Subject::default(), epsilon = 0.0);
+    }
+
+    #[test]
+    fn it_requires_the_mediums_to_be_equal() {
+        let other = Subject { medium: Some(Medium::new()), ..Subject::default() };
+
+        assert!(!Subject::default().abs_diff_eq(&other, 1.0));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_through_json() {
+        let subject = Subject::new(Point3f::new(1.0, 2.0, 3.0), Vector3f::new(0.0, 0.0, 1.0), Some(10.0), Some(0.5), None);
+        let json = serde_json::to_string(&subject).unwrap();
+
+        assert_eq!(json, r#"{"o":[1.0,2.0,3.0],"d":[0.0,0.0,1.0],"t_max":10.0,"time":0.5,"medium":null}"#);
+        assert_eq!(serde_json::from_str::<Subject>(&json).unwrap(), subject);
+    }
+
+    #[test]
+    fn it_stores_an_infinite_t_max_as_null() {
+        let json = serde_json::to_string(&Subject::default()).unwrap();
+        let subject: Subject = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains(r#""t_max":null"#));
+        assert_eq!(subject.t_max, Real::INFINITY);
     }
 }
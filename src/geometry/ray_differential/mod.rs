@@ -2,8 +2,10 @@ use crate::dummy::Medium;
 use super::point3::Point3f;
 use super::vector3::Vector3f;
 use super::ray::Ray;
+use super::float::Real;
 
-#[derive(Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RayDifferential {
     pub ray: Ray,
 
@@ -21,19 +23,18 @@ impl From<Ray> for RayDifferential {
 }
 
 impl RayDifferential {
-    pub fn new(o: Point3f, d: Vector3f, t_max: Option<f64>, time: Option<f64>, medium: Option<Medium>) -> Self {
+    pub fn new(o: Point3f, d: Vector3f, t_max: Option<Real>, time: Option<Real>, medium: Option<Medium>) -> Self {
         Ray::new(o, d, t_max, time, medium).into()
     }
 
-    pub fn scale_differentials(&mut self, s: f64) {
-        let o = &self.ray.o;
-        let d = &self.ray.d;
+    pub fn scale_differentials(&mut self, s: Real) {
+        let Ray { o, d, .. } = self.ray;
 
-        self.rx_origin = o + &(&(&self.rx_origin - o) * s);
-        self.ry_origin = o + &(&(&self.ry_origin - o) * s);
+        self.rx_origin = o + (self.rx_origin - o) * s;
+        self.ry_origin = o + (self.ry_origin - o) * s;
 
-        self.rx_direction = d + &(&(&self.rx_direction - d) * s);
-        self.ry_direction = d + &(&(&self.ry_direction - d) * s);
+        self.rx_direction = d + (self.rx_direction - d) * s;
+        self.ry_direction = d + (self.ry_direction - d) * s;
     }
 }
 
@@ -1,5 +1,4 @@
 use super::*;
-use std::cell::RefCell;
 
 type Subject = RayDifferential;
 
@@ -15,18 +14,18 @@ mod new {
         let medium = Some(Medium::new());
 
         let subject = Subject::new(
-            origin.clone(),
-            direction.clone(),
+            origin,
+            direction,
             t_max,
             time,
-            medium.clone(),
+            medium,
         );
 
         let ray = &subject.ray;
 
         assert_eq!(ray.o, origin);
         assert_eq!(ray.d, direction);
-        assert_eq!(ray.t_max, RefCell::new(123.0));
+        assert_eq!(ray.t_max, 123.0);
         assert_eq!(ray.time, 456.0);
         assert_eq!(ray.medium, medium);
 
@@ -49,7 +48,7 @@ mod conversions {
         let medium = Some(Medium::new());
 
         let ray = Ray::new(origin, direction, t_max, time, medium);
-        let subject: Subject = ray.clone().into();
+        let subject: Subject = ray.into();
 
         assert_eq!(subject.ray, ray);
     }
@@ -79,3 +78,31 @@ mod scale_differentials {
         assert_eq!(subject.ry_direction, Vector3f::new(5.0, 5.0, 5.0));
     }
 }
+
+mod threads {
+    use super::*;
+
+    fn assert_send_sync_copy<T: Send + Sync + Copy>() { }
+
+    #[test]
+    fn it_can_be_shared_and_copied_between_threads() {
+        assert_send_sync_copy::<Subject>();
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_through_json() {
+        let mut subject = Subject::new(Point3f::new(1.0, 2.0, 3.0), Vector3f::new(0.0, 0.0, 1.0), None, None, None);
+        subject.has_differentials = true;
+        subject.rx_origin = Point3f::new(1.1, 2.0, 3.0);
+        subject.ry_direction = Vector3f::new(0.0, 0.1, 1.0);
+
+        let json = serde_json::to_string(&subject).unwrap();
+
+        assert_eq!(serde_json::from_str::<Subject>(&json).unwrap(), subject);
+    }
+}
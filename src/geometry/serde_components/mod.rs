@@ -0,0 +1,42 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeTuple;
+use serde::de::{self, Visitor, SeqAccess};
+use super::dimension_mismatch::components_from_vec;
+
+// Points, vectors and normals are serialized as a plain array of their components,
+// e.g. [1.0, 2.0, 3.0], rather than as a struct with a components field.
+pub fn serialize<T: Serialize, S: Serializer, const N: usize>(components: &[T; N], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(N)?;
+
+    for component in components {
+        tuple.serialize_element(component)?;
+    }
+
+    tuple.end()
+}
+
+pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>, const N: usize>(deserializer: D) -> Result<[T; N], D::Error> {
+    deserializer.deserialize_tuple(N, ComponentsVisitor(PhantomData))
+}
+
+struct ComponentsVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ComponentsVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {} components", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut components = Vec::with_capacity(N);
+
+        while let Some(component) = seq.next_element()? {
+            components.push(component);
+        }
+
+        components_from_vec(components).map_err(|mismatch| de::Error::invalid_length(mismatch.actual, &self))
+    }
+}
@@ -0,0 +1,392 @@
+use std::ops::Mul;
+use super::approx_eq::{ApproxEq, Tolerance};
+use super::matrix4x4::{Matrix4x4, SingularMatrix};
+use super::point3::Point3f;
+use super::vector3::Vector3f;
+use super::normal3::Normal3f;
+use super::ray::Ray;
+use super::ray_differential::RayDifferential;
+use super::bounds::Bounds;
+use super::bounds3::Bounds3f;
+use super::float::{Real, gamma};
+
+// Stores the inverse alongside the matrix so it doesn't need to be recomputed,
+// e.g. for transforming normals or converting rays back into object space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Transform {
+    pub m: Matrix4x4,
+    pub m_inv: Matrix4x4,
+}
+
+impl Transform {
+    pub fn new(m: Matrix4x4) -> Result<Self, SingularMatrix> {
+        let m_inv = m.inverse()?;
+
+        Ok(Self { m, m_inv })
+    }
+
+    pub fn from_matrices(m: Matrix4x4, m_inv: Matrix4x4) -> Self {
+        Self { m, m_inv }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self::from_matrices(self.m_inv, self.m)
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_matrices(self.m.transpose(), self.m_inv.transpose())
+    }
+
+    pub fn translate(delta: &Vector3f) -> Self {
+        let (x, y, z) = (delta.x(), delta.y(), delta.z());
+
+        let m = Matrix4x4::new([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        let m_inv = Matrix4x4::new([
+            [1.0, 0.0, 0.0, -x],
+            [0.0, 1.0, 0.0, -y],
+            [0.0, 0.0, 1.0, -z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m_inv)
+    }
+
+    pub fn scale(x: Real, y: Real, z: Real) -> Self {
+        let m = Matrix4x4::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        let m_inv = Matrix4x4::new([
+            [x.recip(), 0.0, 0.0, 0.0],
+            [0.0, y.recip(), 0.0, 0.0],
+            [0.0, 0.0, z.recip(), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m_inv)
+    }
+
+    // Rotation matrices are orthogonal, so their inverse is their transpose.
+    pub fn rotate_x(degrees: Real) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        let m = Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m.transpose())
+    }
+
+    pub fn rotate_y(degrees: Real) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        let m = Matrix4x4::new([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m.transpose())
+    }
+
+    pub fn rotate_z(degrees: Real) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        let m = Matrix4x4::new([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m.transpose())
+    }
+
+    pub fn rotate(degrees: Real, axis: &Vector3f) -> Self {
+        let a = axis.normalize();
+        let (x, y, z) = (a.x(), a.y(), a.z());
+        let (sin, cos) = degrees.to_radians().sin_cos();
+
+        let m = Matrix4x4::new([
+            [
+                x * x + (1.0 - x * x) * cos,
+                x * y * (1.0 - cos) - z * sin,
+                x * z * (1.0 - cos) + y * sin,
+                0.0,
+            ],
+            [
+                x * y * (1.0 - cos) + z * sin,
+                y * y + (1.0 - y * y) * cos,
+                y * z * (1.0 - cos) - x * sin,
+                0.0,
+            ],
+            [
+                x * z * (1.0 - cos) - y * sin,
+                y * z * (1.0 - cos) + x * sin,
+                z * z + (1.0 - z * z) * cos,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Self::from_matrices(m, m.transpose())
+    }
+
+    // Builds the world-to-camera transform for a camera at pos looking towards look.
+    // Returns an error if up is parallel to the viewing direction.
+    pub fn look_at(pos: &Point3f, look: &Point3f, up: &Vector3f) -> Result<Self, SingularMatrix> {
+        let direction = (look - pos).normalize();
+        let right = up.normalize().cross(&direction);
+
+        if right.length() == 0.0 {
+            return Err(SingularMatrix);
+        }
+
+        let right = right.normalize();
+        let new_up = direction.cross(&right);
+
+        let camera_to_world = Matrix4x4::new([
+            [right.x(), new_up.x(), direction.x(), pos.x()],
+            [right.y(), new_up.y(), direction.y(), pos.y()],
+            [right.z(), new_up.z(), direction.z(), pos.z()],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        Ok(Self::from_matrices(camera_to_world.inverse()?, camera_to_world))
+    }
+
+    // Projects points onto the z=1 viewing plane and maps z from [near, far] to [0, 1].
+    pub fn perspective(fov: Real, near: Real, far: Real) -> Self {
+        let persp = Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, far / (far - near), -far * near / (far - near)],
+            [0.0, 0.0, 1.0, 0.0],
+        ]);
+
+        let persp_inv = Matrix4x4::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, (near - far) / (far * near), 1.0 / near],
+        ]);
+
+        let inverse_tan = (fov.to_radians() / 2.0).tan().recip();
+        let scale = Self::scale(inverse_tan, inverse_tan, 1.0);
+
+        &scale * &Self::from_matrices(persp, persp_inv)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.m == Matrix4x4::identity()
+    }
+
+    pub fn has_scale(&self) -> bool {
+        let la2 = (self * &Vector3f::new(1.0, 0.0, 0.0)).length_squared();
+        let lb2 = (self * &Vector3f::new(0.0, 1.0, 0.0)).length_squared();
+        let lc2 = (self * &Vector3f::new(0.0, 0.0, 1.0)).length_squared();
+
+        let not_one = |x: Real| !(0.999..=1.001).contains(&x);
+
+        not_one(la2) || not_one(lb2) || not_one(lc2)
+    }
+
+    // True if the determinant of the upper-left 3x3 submatrix is negative.
+    pub fn swaps_handedness(&self) -> bool {
+        let m = &self.m.m;
+
+        let determinant =
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1]) -
+            m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0]) +
+            m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+
+        determinant < 0.0
+    }
+}
+
+impl ApproxEq for Transform {
+    type Float = Real;
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Real>) -> bool {
+        self.m.approx_eq(&other.m, tolerance) && self.m_inv.approx_eq(&other.m_inv, tolerance)
+    }
+}
+
+// These return a conservative bound on the absolute floating point error introduced by
+// the transformation, so that intersection code can tell how far a point might be off.
+impl Transform {
+    pub fn transform_point_with_error(&self, p: &Point3f) -> (Point3f, Vector3f) {
+        let m = &self.m.m;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+
+        let error = |i: usize| gamma(3) * (
+            (m[i][0] * x).abs() + (m[i][1] * y).abs() + (m[i][2] * z).abs() + m[i][3].abs()
+        );
+
+        let p_error = Vector3f::new(error(0), error(1), error(2));
+
+        (self * p, p_error)
+    }
+
+    // For points that already carry some error, e.g. from a previous intersection test.
+    pub fn transform_point_with_abs_error(&self, p: &Point3f, p_error: &Vector3f) -> (Point3f, Vector3f) {
+        let m = &self.m.m;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+        let (ex, ey, ez) = (p_error.x(), p_error.y(), p_error.z());
+
+        let error = |i: usize| {
+            let existing = m[i][0].abs() * ex + m[i][1].abs() * ey + m[i][2].abs() * ez;
+            let introduced = (m[i][0] * x).abs() + (m[i][1] * y).abs() + (m[i][2] * z).abs() + m[i][3].abs();
+
+            (gamma(3) + 1.0) * existing + gamma(3) * introduced
+        };
+
+        let p_error = Vector3f::new(error(0), error(1), error(2));
+
+        (self * p, p_error)
+    }
+
+    pub fn transform_vector_with_error(&self, v: &Vector3f) -> (Vector3f, Vector3f) {
+        let m = &self.m.m;
+        let (x, y, z) = (v.x(), v.y(), v.z());
+
+        let error = |i: usize| gamma(3) * (
+            (m[i][0] * x).abs() + (m[i][1] * y).abs() + (m[i][2] * z).abs()
+        );
+
+        let v_error = Vector3f::new(error(0), error(1), error(2));
+
+        (self * v, v_error)
+    }
+
+    // Moves the ray's origin forwards along its direction by the origin's error so that the
+    // transformed ray doesn't start behind the surface it left. The t_max is shortened to match.
+    pub fn transform_ray_with_error(&self, ray: &Ray) -> (Ray, Vector3f, Vector3f) {
+        let (mut o, o_error) = self.transform_point_with_error(&ray.o);
+        let (d, d_error) = self.transform_vector_with_error(&ray.d);
+
+        let mut t_max = ray.t_max;
+        let length_squared = d.length_squared();
+
+        if length_squared > 0.0 {
+            let dt = d.abs().dot(o_error) / length_squared;
+
+            o += d * dt;
+            t_max -= dt;
+        }
+
+        let ray = Ray::new(o, d, Some(t_max), Some(ray.time), ray.medium);
+
+        (ray, o_error, d_error)
+    }
+}
+
+impl Mul<Self> for &Transform {
+    type Output = Transform;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Transform::from_matrices(&self.m * &other.m, &other.m_inv * &self.m_inv)
+    }
+}
+
+impl Mul<&Point3f> for &Transform {
+    type Output = Point3f;
+
+    fn mul(self, p: &Point3f) -> Self::Output {
+        let m = &self.m.m;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+
+        let xp = m[0][0] * x + m[0][1] * y + m[0][2] * z + m[0][3];
+        let yp = m[1][0] * x + m[1][1] * y + m[1][2] * z + m[1][3];
+        let zp = m[2][0] * x + m[2][1] * y + m[2][2] * z + m[2][3];
+        let wp = m[3][0] * x + m[3][1] * y + m[3][2] * z + m[3][3];
+
+        let point = Point3f::new(xp, yp, zp);
+
+        if wp == 1.0 { point } else { point / wp }
+    }
+}
+
+// Vectors are directions so they're unaffected by the translation column.
+impl Mul<&Vector3f> for &Transform {
+    type Output = Vector3f;
+
+    fn mul(self, v: &Vector3f) -> Self::Output {
+        let m = &self.m.m;
+        let (x, y, z) = (v.x(), v.y(), v.z());
+
+        Vector3f::new(
+            m[0][0] * x + m[0][1] * y + m[0][2] * z,
+            m[1][0] * x + m[1][1] * y + m[1][2] * z,
+            m[2][0] * x + m[2][1] * y + m[2][2] * z,
+        )
+    }
+}
+
+// Normals must be transformed by the inverse transpose to stay perpendicular to the surface.
+impl Mul<&Normal3f> for &Transform {
+    type Output = Normal3f;
+
+    fn mul(self, n: &Normal3f) -> Self::Output {
+        let m_inv = &self.m_inv.m;
+        let (x, y, z) = (n.x(), n.y(), n.z());
+
+        Normal3f::new(
+            m_inv[0][0] * x + m_inv[1][0] * y + m_inv[2][0] * z,
+            m_inv[0][1] * x + m_inv[1][1] * y + m_inv[2][1] * z,
+            m_inv[0][2] * x + m_inv[1][2] * y + m_inv[2][2] * z,
+        )
+    }
+}
+
+impl Mul<&Ray> for &Transform {
+    type Output = Ray;
+
+    fn mul(self, ray: &Ray) -> Self::Output {
+        let (ray, _, _) = self.transform_ray_with_error(ray);
+
+        ray
+    }
+}
+
+impl Mul<&RayDifferential> for &Transform {
+    type Output = RayDifferential;
+
+    fn mul(self, rd: &RayDifferential) -> Self::Output {
+        RayDifferential {
+            ray: self * &rd.ray,
+            has_differentials: rd.has_differentials,
+            rx_origin: self * &rd.rx_origin,
+            ry_origin: self * &rd.ry_origin,
+            rx_direction: self * &rd.rx_direction,
+            ry_direction: self * &rd.ry_direction,
+        }
+    }
+}
+
+// Transforms all eight corners since the box may no longer be axis-aligned.
+impl Mul<&Bounds3f> for &Transform {
+    type Output = Bounds3f;
+
+    fn mul(self, bounds: &Bounds3f) -> Self::Output {
+        let first = Bounds::from(&(self * &bounds.corner(0)));
+
+        (1..8).fold(first, |b, i| b.union_point(&(self * &bounds.corner(i))))
+    }
+}
+
+#[cfg(test)]
+mod test;
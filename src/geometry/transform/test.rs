@@ -0,0 +1,515 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = Transform;
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_builds_a_transform_and_computes_the_inverse() {
+        let m = Matrix4x4::new([
+            [2.0, 0.0, 0.0, 1.0],
+            [0.0, 4.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+
+        let subject = Subject::new(m).unwrap();
+
+        assert_eq!(subject.m, m);
+        assert_approx!(&subject.m * &subject.m_inv, Matrix4x4::identity(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_matrix_is_singular() {
+        let m = Matrix4x4::new([[0.0; 4]; 4]);
+
+        assert_eq!(Subject::new(m), Err(SingularMatrix));
+    }
+}
+
+mod default {
+    use super::*;
+
+    #[test]
+    fn it_builds_the_identity_transform() {
+        let subject = Subject::default();
+
+        assert!(subject.is_identity());
+        assert_eq!(subject.m_inv, Matrix4x4::identity());
+    }
+}
+
+mod inverse {
+    use super::*;
+
+    #[test]
+    fn it_swaps_the_matrix_and_its_inverse() {
+        let subject = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0)).inverse();
+        let point = &subject * &Point3f::new(1.0, 2.0, 3.0);
+
+        assert_approx!(point, Point3f::new(0.0, 0.0, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod transpose {
+    use super::*;
+
+    #[test]
+    fn it_transposes_the_matrix_and_its_inverse() {
+        let transform = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0));
+        let subject = transform.transpose();
+
+        assert_eq!(subject.m, transform.m.transpose());
+        assert_eq!(subject.m_inv, transform.m_inv.transpose());
+    }
+}
+
+mod translate {
+    use super::*;
+
+    #[test]
+    fn it_moves_points_by_the_delta() {
+        let subject = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0));
+        let point = &subject * &Point3f::new(1.0, 1.0, 1.0);
+
+        assert_approx!(point, Point3f::new(2.0, 3.0, 4.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_does_not_affect_vectors() {
+        let subject = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0));
+        let vector = &subject * &Vector3f::new(1.0, 1.0, 1.0);
+
+        assert_approx!(vector, Vector3f::new(1.0, 1.0, 1.0), epsilon = 1e-6);
+    }
+}
+
+mod scale {
+    use super::*;
+
+    #[test]
+    fn it_scales_points_and_vectors_on_each_axis() {
+        let subject = Subject::scale(2.0, 3.0, 4.0);
+
+        let point = &subject * &Point3f::new(1.0, 1.0, 1.0);
+        let vector = &subject * &Vector3f::new(1.0, -1.0, 1.0);
+
+        assert_approx!(point, Point3f::new(2.0, 3.0, 4.0), epsilon = 1e-6);
+        assert_approx!(vector, Vector3f::new(2.0, -3.0, 4.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_computes_the_inverse() {
+        let subject = Subject::scale(2.0, 3.0, 4.0);
+
+        assert_approx!(&subject.m * &subject.m_inv, Matrix4x4::identity(), epsilon = 1e-6);
+    }
+}
+
+mod rotate_x {
+    use super::*;
+
+    #[test]
+    fn it_rotates_counter_clockwise_about_the_x_axis() {
+        let subject = Subject::rotate_x(90.0);
+        let vector = &subject * &Vector3f::new(0.0, 1.0, 0.0);
+
+        assert_approx!(vector, Vector3f::new(0.0, 0.0, 1.0), epsilon = 1e-6);
+    }
+}
+
+mod rotate_y {
+    use super::*;
+
+    #[test]
+    fn it_rotates_counter_clockwise_about_the_y_axis() {
+        let subject = Subject::rotate_y(90.0);
+        let vector = &subject * &Vector3f::new(0.0, 0.0, 1.0);
+
+        assert_approx!(vector, Vector3f::new(1.0, 0.0, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod rotate_z {
+    use super::*;
+
+    #[test]
+    fn it_rotates_counter_clockwise_about_the_z_axis() {
+        let subject = Subject::rotate_z(90.0);
+        let vector = &subject * &Vector3f::new(1.0, 0.0, 0.0);
+
+        assert_approx!(vector, Vector3f::new(0.0, 1.0, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod rotate {
+    use super::*;
+
+    #[test]
+    fn it_matches_the_axis_specific_rotations() {
+        let x = Subject::rotate(30.0, &Vector3f::new(1.0, 0.0, 0.0));
+        let y = Subject::rotate(30.0, &Vector3f::new(0.0, 2.0, 0.0));
+        let z = Subject::rotate(30.0, &Vector3f::new(0.0, 0.0, 1.0));
+
+        assert_approx!(x.m, Subject::rotate_x(30.0).m, epsilon = 1e-6);
+        assert_approx!(y.m, Subject::rotate_y(30.0).m, epsilon = 1e-6);
+        assert_approx!(z.m, Subject::rotate_z(30.0).m, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_rotates_about_an_arbitrary_axis() {
+        let subject = Subject::rotate(120.0, &Vector3f::new(1.0, 1.0, 1.0));
+        let vector = &subject * &Vector3f::new(1.0, 0.0, 0.0);
+
+        assert_approx!(vector, Vector3f::new(0.0, 1.0, 0.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_does_not_move_vectors_along_the_axis() {
+        let axis = Vector3f::new(1.0, 2.0, 3.0);
+        let subject = Subject::rotate(45.0, &axis);
+
+        assert_approx!(&subject * &axis, axis, epsilon = 1e-6);
+    }
+}
+
+mod look_at {
+    use super::*;
+
+    #[test]
+    fn it_moves_the_camera_position_to_the_origin() {
+        let pos = Point3f::new(1.0, 2.0, 3.0);
+        let look = Point3f::new(1.0, 2.0, 10.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+
+        let subject = Subject::look_at(&pos, &look, &up).unwrap();
+
+        assert_approx!(&subject * &pos, Point3f::new(0.0, 0.0, 0.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_points_the_viewing_direction_down_the_z_axis() {
+        let pos = Point3f::new(0.0, 0.0, 0.0);
+        let look = Point3f::new(5.0, 0.0, 0.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+
+        let subject = Subject::look_at(&pos, &look, &up).unwrap();
+
+        assert_approx!(&subject * &look, Point3f::new(0.0, 0.0, 5.0), epsilon = 1e-6);
+        assert_approx!(&subject * &up, up, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_up_is_parallel_to_the_viewing_direction() {
+        let pos = Point3f::new(0.0, 0.0, 0.0);
+        let look = Point3f::new(0.0, 5.0, 0.0);
+        let up = Vector3f::new(0.0, 1.0, 0.0);
+
+        assert_eq!(Subject::look_at(&pos, &look, &up), Err(SingularMatrix));
+    }
+}
+
+mod perspective {
+    use super::*;
+
+    #[test]
+    fn it_maps_the_near_and_far_planes_to_zero_and_one() {
+        let subject = Subject::perspective(90.0, 1.0, 100.0);
+
+        let near = &subject * &Point3f::new(0.0, 0.0, 1.0);
+        let far = &subject * &Point3f::new(0.0, 0.0, 100.0);
+
+        assert_approx!(near.z(), 0.0, epsilon = 1e-6);
+        assert_approx!(far.z(), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_divides_x_and_y_by_the_depth_and_scales_by_the_field_of_view() {
+        let subject = Subject::perspective(90.0, 1.0, 100.0);
+        let point = &subject * &Point3f::new(2.0, -4.0, 4.0);
+
+        assert_approx!(point.x(), 0.5, epsilon = 1e-6);
+        assert_approx!(point.y(), -1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_computes_the_inverse() {
+        let subject = Subject::perspective(60.0, 0.5, 20.0);
+
+        assert_approx!(&subject.m * &subject.m_inv, Matrix4x4::identity(), epsilon = 1e-6);
+    }
+}
+
+mod composition {
+    use super::*;
+
+    #[test]
+    fn it_applies_the_right_hand_transform_first() {
+        let translate = Subject::translate(&Vector3f::new(1.0, 0.0, 0.0));
+        let scale = Subject::scale(2.0, 2.0, 2.0);
+
+        let subject = &translate * &scale;
+        let point = &subject * &Point3f::new(1.0, 1.0, 1.0);
+
+        assert_approx!(point, Point3f::new(3.0, 2.0, 2.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_composes_the_inverses_in_reverse_order() {
+        let translate = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0));
+        let rotate = Subject::rotate_y(30.0);
+
+        let subject = &translate * &rotate;
+
+        assert_approx!(&subject.m * &subject.m_inv, Matrix4x4::identity(), epsilon = 1e-6);
+    }
+}
+
+mod is_identity {
+    use super::*;
+
+    #[test]
+    fn it_returns_whether_the_transform_does_nothing() {
+        assert!(Subject::default().is_identity());
+        assert!(!Subject::scale(1.0, 2.0, 1.0).is_identity());
+    }
+}
+
+mod has_scale {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_if_the_transform_scales_any_axis() {
+        assert!(Subject::scale(1.0, 1.0, 2.0).has_scale());
+    }
+
+    #[test]
+    fn it_returns_false_for_rotations_and_translations() {
+        let translate = Subject::translate(&Vector3f::new(1.0, 2.0, 3.0));
+        let rotate = Subject::rotate(33.0, &Vector3f::new(1.0, 2.0, 3.0));
+
+        assert!(!(&translate * &rotate).has_scale());
+    }
+}
+
+mod swaps_handedness {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_if_the_transform_mirrors_an_odd_number_of_axes() {
+        assert!(Subject::scale(-1.0, 1.0, 1.0).swaps_handedness());
+        assert!(!Subject::scale(-1.0, -1.0, 1.0).swaps_handedness());
+    }
+
+    #[test]
+    fn it_returns_false_for_rotations() {
+        assert!(!Subject::rotate_x(120.0).swaps_handedness());
+    }
+}
+
+mod normals {
+    use super::*;
+
+    #[test]
+    fn it_keeps_normals_perpendicular_to_the_surface_under_non_uniform_scale() {
+        let subject = Subject::scale(1.0, 2.0, 1.0);
+
+        let tangent = &subject * &Vector3f::new(1.0, -1.0, 0.0);
+        let normal = &subject * &Normal3f::new(1.0, 1.0, 0.0);
+
+        assert_approx!(tangent.dot(&normal), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_rotates_normals_like_vectors() {
+        let subject = Subject::rotate_z(90.0);
+        let normal = &subject * &Normal3f::new(1.0, 0.0, 0.0);
+
+        assert_approx!(normal, Normal3f::new(0.0, 1.0, 0.0), epsilon = 1e-6);
+    }
+}
+
+mod rays {
+    use super::*;
+    use crate::dummy::Medium;
+
+    #[test]
+    fn it_transforms_the_origin_and_direction_and_keeps_the_other_fields() {
+        let subject = Subject::translate(&Vector3f::new(1.0, 0.0, 0.0));
+
+        let o = Point3f::new(0.0, 0.0, 0.0);
+        let d = Vector3f::new(0.0, 0.0, 1.0);
+        let ray = Ray::new(o, d, Some(10.0), Some(0.5), Some(Medium::new()));
+
+        let transformed = &subject * &ray;
+
+        assert_approx!(transformed.o, Point3f::new(1.0, 0.0, 0.0), epsilon = 1e-6);
+        assert_approx!(transformed.d, Vector3f::new(0.0, 0.0, 1.0), epsilon = 1e-6);
+        assert_eq!(transformed.t_max, 10.0);
+        assert_eq!(transformed.time, 0.5);
+        assert_eq!(transformed.medium, Some(Medium::new()));
+    }
+
+    #[test]
+    fn it_transforms_the_differential_origins_and_directions() {
+        let subject = Subject::scale(2.0, 2.0, 2.0);
+
+        let mut rd = RayDifferential::new(Point3f::new(1.0, 1.0, 1.0), Vector3f::new(0.0, 0.0, 1.0), None, None, None);
+        rd.has_differentials = true;
+        rd.rx_origin = Point3f::new(2.0, 1.0, 1.0);
+        rd.ry_origin = Point3f::new(1.0, 2.0, 1.0);
+        rd.rx_direction = Vector3f::new(0.1, 0.0, 1.0);
+        rd.ry_direction = Vector3f::new(0.0, 0.1, 1.0);
+
+        let transformed = &subject * &rd;
+
+        assert!(transformed.has_differentials);
+        assert_approx!(transformed.ray.o, Point3f::new(2.0, 2.0, 2.0), epsilon = 1e-6);
+        assert_approx!(transformed.rx_origin, Point3f::new(4.0, 2.0, 2.0), epsilon = 1e-6);
+        assert_approx!(transformed.ry_origin, Point3f::new(2.0, 4.0, 2.0), epsilon = 1e-6);
+        assert_approx!(transformed.rx_direction, Vector3f::new(0.2, 0.0, 2.0), epsilon = 1e-6);
+        assert_approx!(transformed.ry_direction, Vector3f::new(0.0, 0.2, 2.0), epsilon = 1e-6);
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_returns_a_box_that_contains_all_eight_transformed_corners() {
+        let subject = Subject::rotate_z(45.0);
+        let bounds = Bounds3f::new(&Point3f::new(0.0, 0.0, 0.0), &Point3f::new(1.0, 1.0, 1.0));
+
+        let transformed = &subject * &bounds;
+        let half_diagonal = (0.5 as Real).sqrt();
+
+        assert_approx!(transformed.p_min, Point3f::new(-half_diagonal, 0.0, 0.0), epsilon = 1e-6);
+        assert_approx!(transformed.p_max, Point3f::new(half_diagonal, 2.0 * half_diagonal, 1.0), epsilon = 1e-6);
+    }
+}
+
+mod transform_point_with_error {
+    use super::*;
+    use crate::geometry::float::gamma;
+
+    #[test]
+    fn it_returns_the_transformed_point_and_a_bound_on_its_error() {
+        let subject = Subject::translate(&Vector3f::new(1.0, 2.0, -3.0));
+        let (point, error) = subject.transform_point_with_error(&Point3f::new(4.0, 5.0, 6.0));
+
+        assert_approx!(point, Point3f::new(5.0, 7.0, 3.0), epsilon = 1e-6);
+
+        assert_eq!(error.x(), gamma(3) * 5.0);
+        assert_eq!(error.y(), gamma(3) * 7.0);
+        assert_eq!(error.z(), gamma(3) * 9.0);
+    }
+
+    #[test]
+    fn it_returns_no_error_for_the_origin_under_a_linear_transform() {
+        let subject = Subject::rotate_x(30.0);
+        let (_, error) = subject.transform_point_with_error(&Point3f::new(0.0, 0.0, 0.0));
+
+        assert_eq!(error, Vector3f::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_bounds_the_actual_error_of_the_transformation() {
+        let subject = &Subject::rotate(37.0, &Vector3f::new(1.0, 2.0, 3.0)) * &Subject::scale(3.0, 0.1, 7.0);
+        let point = Point3f::new(0.1, 123.456, -9.87);
+
+        let (transformed, error) = subject.transform_point_with_error(&point);
+        let round_trip = &subject.inverse() * &transformed;
+
+        let (_, inverse_error) = subject.inverse().transform_point_with_abs_error(&transformed, &error);
+
+        assert!((round_trip.x() - point.x()).abs() <= inverse_error.x());
+        assert!((round_trip.y() - point.y()).abs() <= inverse_error.y());
+        assert!((round_trip.z() - point.z()).abs() <= inverse_error.z());
+    }
+}
+
+mod transform_point_with_abs_error {
+    use super::*;
+    use crate::geometry::float::gamma;
+
+    #[test]
+    fn it_carries_the_existing_error_through_the_transform() {
+        let subject = Subject::scale(2.0, 2.0, 2.0);
+        let p_error = Vector3f::new(1.0, 0.0, 0.0);
+
+        let (_, error) = subject.transform_point_with_abs_error(&Point3f::new(0.0, 0.0, 0.0), &p_error);
+
+        assert_eq!(error.x(), (gamma(3) + 1.0) * 2.0);
+        assert_eq!(error.y(), 0.0);
+        assert_eq!(error.z(), 0.0);
+    }
+}
+
+mod transform_vector_with_error {
+    use super::*;
+    use crate::geometry::float::gamma;
+
+    #[test]
+    fn it_ignores_the_translation_when_bounding_the_error() {
+        let subject = Subject::translate(&Vector3f::new(100.0, 100.0, 100.0));
+        let (vector, error) = subject.transform_vector_with_error(&Vector3f::new(1.0, -2.0, 0.0));
+
+        assert_approx!(vector, Vector3f::new(1.0, -2.0, 0.0), epsilon = 1e-6);
+
+        assert_eq!(error.x(), gamma(3) * 1.0);
+        assert_eq!(error.y(), gamma(3) * 2.0);
+        assert_eq!(error.z(), 0.0);
+    }
+}
+
+mod transform_ray_with_error {
+    use super::*;
+
+    #[test]
+    fn it_moves_the_origin_along_the_direction_by_the_error() {
+        let subject = Subject::translate(&Vector3f::new(10.0, 0.0, 0.0));
+        let ray = Ray::new(Point3f::new(0.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), Some(5.0), None, None);
+
+        let (transformed, o_error, _) = subject.transform_ray_with_error(&ray);
+        let dt = o_error.x();
+
+        assert!(dt > 0.0);
+        assert_eq!(transformed.o.x(), 10.0 + dt);
+        assert_eq!(transformed.t_max, 5.0 - dt);
+    }
+
+    #[test]
+    fn it_does_not_move_the_origin_if_the_direction_is_zero() {
+        let subject = Subject::translate(&Vector3f::new(10.0, 0.0, 0.0));
+        let ray = Ray::new(Point3f::new(1.0, 0.0, 0.0), Vector3f::new(0.0, 0.0, 0.0), Some(5.0), None, None);
+
+        let (transformed, _, _) = subject.transform_ray_with_error(&ray);
+
+        assert_eq!(transformed.o.x(), 11.0);
+        assert_eq!(transformed.t_max, 5.0);
+    }
+
+    #[test]
+    fn it_is_used_when_multiplying_a_ray_by_a_transform() {
+        let subject = Subject::translate(&Vector3f::new(10.0, 0.0, 0.0));
+        let ray = Ray::new(Point3f::new(0.0, 0.0, 0.0), Vector3f::new(1.0, 0.0, 0.0), None, None, None);
+
+        let (expected, _, _) = subject.transform_ray_with_error(&ray);
+
+        assert_eq!(&subject * &ray, expected);
+    }
+}
+
+mod approx_eq {
+    use super::*;
+
+    #[test]
+    fn it_compares_the_matrix_and_its_inverse() {
+        let subject = Subject::rotate_z(90.0);
+
+        assert_approx!(&subject * &Subject::rotate_z(-90.0), Subject::default(), epsilon = 1e-12);
+        assert!(!subject.abs_diff_eq(&Subject::rotate_z(91.0), 1e-6));
+    }
+}
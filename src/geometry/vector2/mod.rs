@@ -1,11 +1,11 @@
-use generic_array::{arr, arr_impl, typenum::U2};
 use super::vector::*;
+use super::float::Real;
 
-pub type Vector2<T> = Vector<T, U2>;
+pub type Vector2<T> = Vector<T, 2>;
 
 impl<T: Copy> Vector2<T> {
     pub fn new(x: T, y: T) -> Self {
-        Self { components: arr![T; x, y] }
+        Self { components: [x, y] }
     }
 
     pub fn x(&self) -> T {
@@ -21,7 +21,7 @@ impl<T: Copy> Vector2<T> {
     }
 }
 
-pub type Vector2f = Vector2<f64>;
+pub type Vector2f = Vector2<Real>;
 pub type Vector2i = Vector2<i32>;
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
-use assert_approx_eq::assert_approx_eq;
 use super::*;
+use crate::assert_approx;
+use std::convert::TryFrom;
+use crate::geometry::dimension_mismatch::DimensionMismatch;
 use crate::geometry::point2::Point2;
 
 type Subject<T> = Vector2<T>;
@@ -33,12 +35,12 @@ mod aliases {
 
     #[test]
     fn it_has_a_type_alias_for_a_vector_of_signed_integers() {
-        Vector2i::new(-1 as i32, 2);
+        Vector2i::new(-1_i32, 2);
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_vector_of_double_precision_floats() {
-        Vector2f::new(0.1 as f64, 0.2);
+    fn it_has_a_type_alias_for_a_vector_of_reals() {
+        Vector2f::new(0.1 as Real, 0.2);
     }
 }
 
@@ -54,15 +56,17 @@ mod default {
     }
 }
 
-mod clone {
+mod copy {
     use super::*;
 
     #[test]
-    fn it_clones_the_vector() {
-        let subject = Subject::new(1, 2).clone();
+    fn it_copies_the_vector() {
+        let original = Subject::new(1, 2);
+        let subject = original;
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
+        assert_eq!(original.x(), 1);
     }
 }
 
@@ -70,13 +74,43 @@ mod conversions {
     use super::*;
 
     #[test]
-    fn it_can_build_a_vector2_from_an_iterator() {
-        let subject: Subject<_> = (1..3).into();
+    fn it_can_build_a_vector2_from_an_array() {
+        let subject: Subject<_> = [1, 2].into();
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
     }
 
+    #[test]
+    fn it_can_try_to_build_a_vector2_from_a_slice() {
+        let subject = Subject::try_from(&[1, 2][..]).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result = Subject::try_from(&[1][..]);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 2, actual: 1 }));
+    }
+
+    #[test]
+    fn it_can_try_to_build_a_vector2_from_an_iterator() {
+        let subject = Subject::try_from_iter(1..3).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_iterator_has_the_wrong_length() {
+        let result = Subject::<i32>::try_from_iter(1..10);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 2, actual: 9 }));
+    }
+
     #[test]
     fn it_can_build_a_vector2_from_a_point2() {
         let point = &Point2::new(1, 2);
@@ -95,7 +129,7 @@ mod addition {
         let a = Subject::new(1, 2);
         let b = Subject::new(3, 4);
 
-        let subject = &a + &b;
+        let subject = a + b;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 6);
@@ -122,7 +156,7 @@ mod subtraction {
         let a = Subject::new(5, 5);
         let b = Subject::new(1, 2);
 
-        let subject = &a - &b;
+        let subject = a - b;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 3);
@@ -168,20 +202,20 @@ mod division {
 
     #[test]
     fn it_divides_by_a_divisor() {
-        let subject = &Subject::new(1, 2) / 10;
+        let subject = &Subject::<i32>::new(1, 2) / 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
     }
 
     #[test]
     fn it_can_mutate_the_vector() {
-        let mut subject = Subject::new(1.0, 2.0);
+        let mut subject = Subject::<f64>::new(1.0, 2.0);
 
-        subject /= 10;
+        subject /= 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
     }
 }
 
@@ -225,7 +259,7 @@ mod dot {
         let a = Subject::new(1, 2);
         let b = Subject::new(3, 4);
 
-        assert_eq!(a.dot(&b), 11);
+        assert_eq!(a.dot(b), 11);
     }
 }
 
@@ -237,7 +271,7 @@ mod abs_dot {
         let a = Subject::new(1, 2);
         let b = Subject::new(-3, -4);
 
-        assert_eq!(a.abs_dot(&b), 11);
+        assert_eq!(a.abs_dot(b), 11);
     }
 }
 
@@ -248,7 +282,7 @@ mod length_squared {
     fn it_returns_the_square_of_the_length_of_the_vector() {
         let subject = Subject::new(1, 2);
 
-        assert_eq!(subject.length_squared(), 5.0);
+        assert_eq!(subject.length_squared(), 5.0 as Real);
     }
 }
 
@@ -259,7 +293,7 @@ mod length {
     fn it_returns_the_length_of_the_vector() {
         let subject = Subject::new(1, 2);
 
-        assert_eq!(subject.length(), f64::sqrt(5.0));
+        assert_eq!(subject.length(), Real::sqrt(5.0));
     }
 }
 
@@ -269,7 +303,7 @@ mod normalize {
     #[test]
     fn it_divides_each_component_by_the_vectors_length() {
         let subject = Subject::new(1, 2).normalize();
-        let divisor = f64::sqrt(5.0);
+        let divisor = Real::sqrt(5.0);
 
         assert_eq!(subject.x(), 1.0 / divisor);
         assert_eq!(subject.y(), 2.0 / divisor);
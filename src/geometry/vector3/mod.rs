@@ -1,11 +1,11 @@
-use generic_array::{arr, arr_impl, typenum::U3};
 use super::vector::*;
+use super::float::{Float, Real, Scalar};
 
-pub type Vector3<T> = Vector<T, U3>;
+pub type Vector3<T> = Vector<T, 3>;
 
 impl<T: Copy> Vector3<T> {
     pub fn new(x: T, y: T, z: T) -> Self {
-        Self { components: arr![T; x, y, z] }
+        Self { components: [x, y, z] }
     }
 
     pub fn x(&self) -> T {
@@ -28,20 +28,105 @@ impl<T: Copy> Vector3<T> {
 }
 
 // This isn't applicable in two dimensions and is hard to generalise to > 3 dimensions.
-impl<T: Into<f64> + Copy> Vector3<T> {
-    pub fn cross(&self, other: &Self) -> Vector3f {
-        let v1 = self.components.iter().map(|&a| a.into()).collect::<Vec<f64>>();
-        let v2 = other.components.iter().map(|&b| b.into()).collect::<Vec<f64>>();
+impl<T: Scalar> Vector3<T> {
+    pub fn cross(&self, other: &Self) -> Vector3<T::Float> {
+        let (x1, y1, z1) = (self.x().to_float(), self.y().to_float(), self.z().to_float());
+        let (x2, y2, z2) = (other.x().to_float(), other.y().to_float(), other.z().to_float());
 
-        let x = (v1[1] * v2[2]) - (v1[2] * v2[1]);
-        let y = (v1[2] * v2[0]) - (v1[0] * v2[2]);
-        let z = (v1[0] * v2[1]) - (v1[1] * v2[0]);
+        let x = (y1 * z2) - (z1 * y2);
+        let y = (z1 * x2) - (x1 * z2);
+        let z = (x1 * y2) - (y1 * x2);
 
-        Vector3f::new(x, y, z)
+        Vector3::new(x, y, z)
     }
 }
 
-pub type Vector3f = Vector3<f64>;
+// Spherical coordinates use theta for the angle from the z axis and phi for the angle
+// around it from the x axis. In a shading frame the z axis is the surface normal.
+impl<F: Float> Vector3<F> {
+    pub fn spherical_direction(sin_theta: F, cos_theta: F, phi: F) -> Self {
+        let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+
+        Self::new(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta)
+    }
+
+    // Like spherical_direction but relative to a coordinate system given by three basis vectors.
+    pub fn spherical_direction_in_basis(sin_theta: F, cos_theta: F, phi: F, x: &Self, y: &Self, z: &Self) -> Self {
+        let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+
+        let mut direction = x * (sin_theta * cos_phi);
+        direction += y * (sin_theta * sin_phi);
+        direction += z * cos_theta;
+
+        direction
+    }
+
+    // These assume the vector is normalized.
+    pub fn spherical_theta(&self) -> F {
+        self.z().clamp(-F::ONE, F::ONE).acos()
+    }
+
+    pub fn spherical_phi(&self) -> F {
+        let phi = self.y().atan2(self.x());
+
+        if phi < F::ZERO { phi + F::PI + F::PI } else { phi }
+    }
+
+    pub fn cos_theta(&self) -> F {
+        self.z()
+    }
+
+    pub fn cos2_theta(&self) -> F {
+        self.z() * self.z()
+    }
+
+    pub fn abs_cos_theta(&self) -> F {
+        self.z().abs()
+    }
+
+    pub fn sin2_theta(&self) -> F {
+        (F::ONE - self.cos2_theta()).max(F::ZERO)
+    }
+
+    pub fn sin_theta(&self) -> F {
+        self.sin2_theta().sqrt()
+    }
+
+    pub fn tan_theta(&self) -> F {
+        self.sin_theta() / self.cos_theta()
+    }
+
+    pub fn tan2_theta(&self) -> F {
+        self.sin2_theta() / self.cos2_theta()
+    }
+
+    // Phi is undefined when the vector is parallel to the z axis so pick phi = 0.
+    pub fn cos_phi(&self) -> F {
+        let sin_theta = self.sin_theta();
+
+        if sin_theta == F::ZERO { F::ONE } else { (self.x() / sin_theta).clamp(-F::ONE, F::ONE) }
+    }
+
+    pub fn sin_phi(&self) -> F {
+        let sin_theta = self.sin_theta();
+
+        if sin_theta == F::ZERO { F::ZERO } else { (self.y() / sin_theta).clamp(-F::ONE, F::ONE) }
+    }
+
+    pub fn cos2_phi(&self) -> F {
+        self.cos_phi() * self.cos_phi()
+    }
+
+    pub fn sin2_phi(&self) -> F {
+        self.sin_phi() * self.sin_phi()
+    }
+
+    pub fn same_hemisphere(&self, other: &Self) -> bool {
+        self.z() * other.z() > F::ZERO
+    }
+}
+
+pub type Vector3f = Vector3<Real>;
 pub type Vector3i = Vector3<i32>;
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use super::*;
-use assert_approx_eq::assert_approx_eq;
+use crate::assert_approx;
+use std::convert::TryFrom;
+use crate::geometry::dimension_mismatch::DimensionMismatch;
 use crate::geometry::point3::Point3;
 use crate::geometry::normal3::Normal3;
 
@@ -35,12 +37,12 @@ mod aliases {
 
     #[test]
     fn it_has_a_type_alias_for_a_vector_of_signed_integers() {
-        Vector3i::new(-1 as i32, 2, -3);
+        Vector3i::new(-1_i32, 2, -3);
     }
 
     #[test]
-    fn it_has_a_type_alias_for_a_vector_of_double_precision_floats() {
-        Vector3f::new(0.1 as f64, 0.2, 0.3);
+    fn it_has_a_type_alias_for_a_vector_of_reals() {
+        Vector3f::new(0.1 as Real, 0.2, 0.3);
     }
 }
 
@@ -57,16 +59,18 @@ mod default {
     }
 }
 
-mod clone {
+mod copy {
     use super::*;
 
     #[test]
-    fn it_clones_the_vector() {
-        let subject = Subject::new(1, 2, 3).clone();
+    fn it_copies_the_vector() {
+        let original = Subject::new(1, 2, 3);
+        let subject = original;
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
+        assert_eq!(original.x(), 1);
     }
 }
 
@@ -74,14 +78,46 @@ mod conversions {
     use super::*;
 
     #[test]
-    fn it_can_build_a_vector3_from_an_iterator() {
-        let subject: Subject<_> = (1..4).into();
+    fn it_can_build_a_vector3_from_an_array() {
+        let subject: Subject<_> = [1, 2, 3].into();
 
         assert_eq!(subject.x(), 1);
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
 
+    #[test]
+    fn it_can_try_to_build_a_vector3_from_a_slice() {
+        let subject = Subject::try_from(&[1, 2, 3][..]).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_slice_has_the_wrong_length() {
+        let result = Subject::try_from(&[1, 2][..]);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 3, actual: 2 }));
+    }
+
+    #[test]
+    fn it_can_try_to_build_a_vector3_from_an_iterator() {
+        let subject = Subject::try_from_iter(1..4).unwrap();
+
+        assert_eq!(subject.x(), 1);
+        assert_eq!(subject.y(), 2);
+        assert_eq!(subject.z(), 3);
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_iterator_has_the_wrong_length() {
+        let result = Subject::<i32>::try_from_iter(1..10);
+
+        assert_eq!(result, Err(DimensionMismatch { expected: 3, actual: 9 }));
+    }
+
     #[test]
     fn it_can_build_a_vector3_from_a_point3() {
         let point = &Point3::new(1, 2, 3);
@@ -103,6 +139,24 @@ mod conversions {
     }
 }
 
+mod cast {
+    use super::*;
+
+    #[test]
+    fn it_converts_the_components_to_another_type() {
+        let subject: Vector3f = Vector3i::new(1, -2, 3).cast();
+
+        assert_eq!(subject, Vector3f::new(1.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn it_returns_none_if_a_component_cannot_be_represented() {
+        let subject = Vector3f::new(1.0, Real::INFINITY, 3.0).try_cast::<i32>();
+
+        assert_eq!(subject, None);
+    }
+}
+
 mod addition {
     use super::*;
 
@@ -111,7 +165,7 @@ mod addition {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(4, 5, 6);
 
-        let subject = &a + &b;
+        let subject = a + b;
 
         assert_eq!(subject.x(), 5);
         assert_eq!(subject.y(), 7);
@@ -130,6 +184,33 @@ mod addition {
         assert_eq!(subject.y(), 7);
         assert_eq!(subject.z(), 9);
     }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_can_add_references() {
+        let a = Subject::new(1, 2, 3);
+        let b = Subject::new(4, 5, 6);
+
+        assert_eq!(&a + &b, a + b);
+    }
+
+    #[test]
+    fn it_can_add_assign_by_value() {
+        let mut subject = Subject::new(1, 2, 3);
+        subject += Subject::new(4, 5, 6);
+
+        assert_eq!(subject, Subject::new(5, 7, 9));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn it_can_mix_owned_and_borrowed_operands() {
+        let a = Subject::new(1, 2, 3);
+        let b = Subject::new(4, 5, 6);
+
+        assert_eq!(&a + b, a + b);
+        assert_eq!(a + &b, a + b);
+    }
 }
 
 mod subtraction {
@@ -140,7 +221,7 @@ mod subtraction {
         let a = Subject::new(5, 5, 5);
         let b = Subject::new(1, 2, 3);
 
-        let subject = &a - &b;
+        let subject = a - b;
 
         assert_eq!(subject.x(), 4);
         assert_eq!(subject.y(), 3);
@@ -183,6 +264,14 @@ mod multiplication {
         assert_eq!(subject.y(), 8);
         assert_eq!(subject.z(), 12);
     }
+
+    #[test]
+    fn it_multiplies_a_scalar_by_a_vector() {
+        let subject = Subject::new(1, 2, 3);
+
+        assert_eq!(4 * subject, Subject::new(4, 8, 12));
+        assert_eq!(0.5 * &Vector3f::new(1.0, 2.0, 3.0), Vector3f::new(0.5, 1.0, 1.5));
+    }
 }
 
 mod division {
@@ -190,22 +279,22 @@ mod division {
 
     #[test]
     fn it_divides_by_a_divisor() {
-        let subject = &Subject::new(1, 2, 3) / 10;
+        let subject = &Subject::<i32>::new(1, 2, 3) / 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 
     #[test]
     fn it_can_mutate_the_vector() {
-        let mut subject = Subject::new(1.0, 2.0, 3.0);
+        let mut subject = Subject::<f64>::new(1.0, 2.0, 3.0);
 
-        subject /= 10;
+        subject /= 10.0;
 
-        assert_approx_eq!(subject.x(), 0.1);
-        assert_approx_eq!(subject.y(), 0.2);
-        assert_approx_eq!(subject.z(), 0.3);
+        assert_approx!(subject.x(), 0.1, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.2, epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.3, epsilon = 1e-6);
     }
 }
 
@@ -252,7 +341,7 @@ mod dot {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(4, 5, 6);
 
-        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.dot(b), 32);
     }
 
     #[test]
@@ -272,7 +361,7 @@ mod abs_dot {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(-4, -5, -6);
 
-        assert_eq!(a.abs_dot(&b), 32);
+        assert_eq!(a.abs_dot(b), 32);
     }
 
     #[test]
@@ -297,9 +386,161 @@ mod cross {
 
         let subject = a.cross(&b);
 
-        assert_eq!(subject.x(), -1.0);
-        assert_eq!(subject.y(), -4.0);
-        assert_eq!(subject.z(), 3.0);
+        assert_eq!(subject.x(), -1.0 as Real);
+        assert_eq!(subject.y(), -4.0 as Real);
+        assert_eq!(subject.z(), 3.0 as Real);
+    }
+
+    #[test]
+    fn it_keeps_the_precision_of_float_vectors() {
+        let a = Subject::new(1.0_f32, 0.0, 0.0);
+        let b = Subject::new(0.0_f32, 1.0, 0.0);
+
+        let subject: Vector3<f32> = a.cross(&b);
+
+        assert_eq!(subject, Subject::new(0.0, 0.0, 1.0));
+    }
+}
+
+mod spherical_direction {
+    use super::*;
+
+    #[test]
+    fn it_converts_spherical_coordinates_to_a_direction() {
+        let theta = Real::PI / 3.0;
+        let subject = Vector3f::spherical_direction(theta.sin(), theta.cos(), Real::PI / 2.0);
+
+        assert_approx!(subject.x(), 0.0, epsilon = 1e-6);
+        assert_approx!(subject.y(), theta.sin(), epsilon = 1e-6);
+        assert_approx!(subject.z(), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_the_z_axis_when_theta_is_zero() {
+        let subject = Vector3f::spherical_direction(0.0, 1.0, 1.234);
+
+        assert_eq!(subject, Vector3f::new(0.0, 0.0, 1.0));
+    }
+}
+
+mod spherical_direction_in_basis {
+    use super::*;
+
+    #[test]
+    fn it_converts_spherical_coordinates_relative_to_the_basis_vectors() {
+        let x = Vector3f::new(0.0, 1.0, 0.0);
+        let y = Vector3f::new(0.0, 0.0, 1.0);
+        let z = Vector3f::new(1.0, 0.0, 0.0);
+
+        let subject = Vector3f::spherical_direction_in_basis(1.0, 0.0, Real::PI / 2.0, &x, &y, &z);
+
+        assert_approx!(subject.x(), 0.0, epsilon = 1e-6);
+        assert_approx!(subject.y(), 0.0, epsilon = 1e-6);
+        assert_approx!(subject.z(), 1.0, epsilon = 1e-6);
+    }
+}
+
+mod spherical_theta {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_angle_from_the_z_axis() {
+        assert_approx!(Vector3f::new(0.0, 0.0, 1.0).spherical_theta(), 0.0, epsilon = 1e-6);
+        assert_approx!(Vector3f::new(1.0, 0.0, 0.0).spherical_theta(), Real::PI / 2.0, epsilon = 1e-6);
+        assert_approx!(Vector3f::new(0.0, 0.0, -1.0).spherical_theta(), Real::PI, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_clamps_z_to_avoid_nans_from_rounding_errors() {
+        let subject = Vector3f::new(0.0, 0.0, 1.0 + 1e-15);
+
+        assert_eq!(subject.spherical_theta(), 0.0);
+    }
+}
+
+mod spherical_phi {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_angle_around_the_z_axis_in_the_range_zero_to_two_pi() {
+        assert_approx!(Vector3f::new(1.0, 0.0, 0.0).spherical_phi(), 0.0, epsilon = 1e-6);
+        assert_approx!(Vector3f::new(0.0, 1.0, 0.0).spherical_phi(), Real::PI / 2.0, epsilon = 1e-6);
+        assert_approx!(Vector3f::new(0.0, -1.0, 0.0).spherical_phi(), 3.0 * Real::PI / 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_round_trips_with_spherical_direction() {
+        let theta: Real = 0.7;
+        let subject = Vector3f::spherical_direction(theta.sin(), theta.cos(), 4.0);
+
+        assert_approx!(subject.spherical_theta(), 0.7, epsilon = 1e-6);
+        assert_approx!(subject.spherical_phi(), 4.0, epsilon = 1e-6);
+    }
+}
+
+mod shading_frame_trigonometry {
+    use super::*;
+
+    fn example() -> Vector3f {
+        // theta = 60 degrees, phi = 30 degrees
+        let sin_theta = (3.0 as Real).sqrt() / 2.0;
+
+        Vector3f::new(sin_theta * (3.0 as Real).sqrt() / 2.0, sin_theta * 0.5, 0.5)
+    }
+
+    #[test]
+    fn it_returns_trigonometric_functions_of_theta() {
+        let subject = example();
+
+        assert_approx!(subject.cos_theta(), 0.5, epsilon = 1e-6);
+        assert_approx!(subject.cos2_theta(), 0.25, epsilon = 1e-6);
+        assert_approx!(subject.sin_theta(), (3.0 as Real).sqrt() / 2.0, epsilon = 1e-6);
+        assert_approx!(subject.sin2_theta(), 0.75, epsilon = 1e-6);
+        assert_approx!(subject.tan_theta(), (3.0 as Real).sqrt(), epsilon = 1e-6);
+        assert_approx!(subject.tan2_theta(), 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_trigonometric_functions_of_phi() {
+        let subject = example();
+
+        assert_approx!(subject.cos_phi(), (3.0 as Real).sqrt() / 2.0, epsilon = 1e-6);
+        assert_approx!(subject.sin_phi(), 0.5, epsilon = 1e-6);
+        assert_approx!(subject.cos2_phi(), 0.75, epsilon = 1e-6);
+        assert_approx!(subject.sin2_phi(), 0.25, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn it_returns_the_absolute_cosine_of_theta() {
+        assert_eq!(Vector3f::new(0.0, 0.6, -0.8).abs_cos_theta(), 0.8);
+    }
+
+    #[test]
+    fn it_picks_phi_of_zero_when_the_vector_is_parallel_to_the_z_axis() {
+        let subject = Vector3f::new(0.0, 0.0, 1.0);
+
+        assert_eq!(subject.cos_phi(), 1.0);
+        assert_eq!(subject.sin_phi(), 0.0);
+    }
+}
+
+mod same_hemisphere {
+    use super::*;
+
+    #[test]
+    fn it_returns_true_if_both_vectors_are_on_the_same_side_of_the_xy_plane() {
+        let a = Vector3f::new(1.0, 2.0, 0.5);
+        let b = Vector3f::new(-3.0, 0.0, 0.1);
+
+        assert!(a.same_hemisphere(&b));
+    }
+
+    #[test]
+    fn it_returns_false_if_the_vectors_are_on_opposite_sides() {
+        let a = Vector3f::new(1.0, 2.0, 0.5);
+        let b = Vector3f::new(1.0, 2.0, -0.5);
+
+        assert!(!a.same_hemisphere(&b));
     }
 }
 
@@ -310,7 +551,7 @@ mod length_squared {
     fn it_returns_the_square_of_the_length_of_the_vector() {
         let subject = Subject::new(1, 2, 3);
 
-        assert_eq!(subject.length_squared(), 14.0);
+        assert_eq!(subject.length_squared(), 14.0 as Real);
     }
 }
 
@@ -321,7 +562,14 @@ mod length {
     fn it_returns_the_length_of_the_vector() {
         let subject = Subject::new(1, 2, 3);
 
-        assert_eq!(subject.length(), f64::sqrt(14.0));
+        assert_eq!(subject.length(), Real::sqrt(14.0));
+    }
+
+    #[test]
+    fn it_returns_the_length_in_the_precision_of_the_components() {
+        let subject = Subject::new(1.0_f32, 2.0, 3.0);
+
+        assert_eq!(subject.length(), f32::sqrt(14.0));
     }
 }
 
@@ -331,7 +579,7 @@ mod normalize {
     #[test]
     fn it_divides_each_component_by_the_vectors_length() {
         let subject = Subject::new(1, 2, 3).normalize();
-        let divisor = f64::sqrt(14.0);
+        let divisor = Real::sqrt(14.0);
 
         assert_eq!(subject.x(), 1.0 / divisor);
         assert_eq!(subject.y(), 2.0 / divisor);
@@ -348,6 +596,27 @@ mod min_component {
 
         assert_eq!(subject.min_component(), 1);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let subject = Vector3f::new(0.5, -1.5, 2.5);
+
+        assert_eq!(subject.min_component(), -1.5);
+    }
+
+    #[test]
+    fn it_ignores_nan_components() {
+        let subject = Vector3f::new(Real::NAN, 2.0, 1.0);
+
+        assert_eq!(subject.min_component(), 1.0);
+    }
+
+    #[test]
+    fn it_returns_nan_if_every_component_is_nan() {
+        let nan = Real::NAN;
+
+        assert!(Vector3f::new(nan, nan, nan).min_component().is_nan());
+    }
 }
 
 mod max_component {
@@ -359,6 +628,20 @@ mod max_component {
 
         assert_eq!(subject.max_component(), 3);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let subject = Vector3f::new(0.5, -1.5, 2.5);
+
+        assert_eq!(subject.max_component(), 2.5);
+    }
+
+    #[test]
+    fn it_ignores_nan_components() {
+        let subject = Vector3f::new(1.0, 2.0, Real::NAN);
+
+        assert_eq!(subject.max_component(), 2.0);
+    }
 }
 
 mod min_dimension {
@@ -370,6 +653,13 @@ mod min_dimension {
 
         assert_eq!(subject.min_dimension(), 0);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let subject = Vector3f::new(0.5, -1.5, 2.5);
+
+        assert_eq!(subject.min_dimension(), 1);
+    }
 }
 
 mod max_dimension {
@@ -381,6 +671,27 @@ mod max_dimension {
 
         assert_eq!(subject.max_dimension(), 2);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let subject = Vector3f::new(0.5, 3.5, 2.5);
+
+        assert_eq!(subject.max_dimension(), 1);
+    }
+
+    #[test]
+    fn it_skips_over_nan_components() {
+        let subject = Vector3f::new(Real::NAN, 1.0, 2.0);
+
+        assert_eq!(subject.max_dimension(), 2);
+    }
+
+    #[test]
+    fn it_returns_zero_if_every_component_is_nan() {
+        let nan = Real::NAN;
+
+        assert_eq!(Vector3f::new(nan, nan, nan).max_dimension(), 0);
+    }
 }
 
 mod min {
@@ -397,6 +708,14 @@ mod min {
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
+
+    #[test]
+    fn it_works_for_floats_and_ignores_nans() {
+        let a = Vector3f::new(1.0, Real::NAN, 3.0);
+        let b = Vector3f::new(2.0, 2.0, -3.0);
+
+        assert_eq!(a.min(&b), Vector3f::new(1.0, 2.0, -3.0));
+    }
 }
 
 mod max {
@@ -413,6 +732,14 @@ mod max {
         assert_eq!(subject.y(), 2);
         assert_eq!(subject.z(), 3);
     }
+
+    #[test]
+    fn it_works_for_floats_and_ignores_nans() {
+        let a = Vector3f::new(1.0, 2.0, 3.0);
+        let b = Vector3f::new(2.0, Real::NAN, -3.0);
+
+        assert_eq!(a.max(&b), Vector3f::new(2.0, 2.0, 3.0));
+    }
 }
 
 mod permute {
@@ -448,7 +775,7 @@ mod face_forward {
         let a = Subject::new(1, 2, 3);
         let b = Subject::new(-5, -5, -5);
 
-        let subject = a.face_forward(&b);
+        let subject = a.face_forward(b);
 
         assert_eq!(subject.x(), -1);
         assert_eq!(subject.y(), -2);
@@ -466,4 +793,116 @@ mod face_forward {
         assert_eq!(subject.y(), -2);
         assert_eq!(subject.z(), -3);
     }
+
+    #[test]
+    fn it_works_for_floats() {
+        let vector = Vector3f::new(0.5, 0.0, 0.0);
+        let normal = Normal3::new(-1.0, 0.1, 0.0);
+
+        assert_eq!(vector.face_forward(&normal), Vector3f::new(-0.5, 0.0, 0.0));
+        assert_eq!(vector.face_forward(Vector3f::new(1.0, 0.0, 0.0)), vector);
+    }
+}
+
+mod approx_eq {
+    use super::*;
+    use crate::geometry::approx_eq::ApproxEq;
+
+    #[test]
+    fn it_compares_each_component() {
+        let subject = Vector3f::new(1.0, 2.0, 3.0);
+
+        assert!(subject.relative_eq(&Vector3f::new(1.0, 2.0, 3.001), 0.001));
+        assert!(!subject.relative_eq(&Vector3f::new(1.01, 2.0, 3.0), 0.001));
+    }
+
+    #[test]
+    fn it_can_be_asserted() {
+        let subject = Vector3f::new(1.0, 1.0, 0.0).normalize() * Real::sqrt(2.0);
+
+        assert_approx!(subject, Vector3f::new(1.0, 1.0, 0.0));
+    }
+}
+
+mod display {
+    use super::*;
+
+    #[test]
+    fn it_formats_the_components_in_parentheses() {
+        assert_eq!(Vector3f::new(1.5, -2.0, 0.0).to_string(), "(1.5, -2, 0)");
+    }
+}
+
+mod from_str {
+    use super::*;
+
+    #[test]
+    fn it_parses_the_displayed_format() {
+        assert_eq!("(1, -2, 3)".parse(), Ok(Subject::new(1, -2, 3)));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+
+    #[test]
+    fn it_round_trips_through_json() {
+        let subject = Vector3f::new(0.1, -0.2, 0.3);
+        let json = serde_json::to_string(&subject).unwrap();
+
+        assert_eq!(json, "[0.1,-0.2,0.3]");
+        assert_eq!(serde_json::from_str::<Vector3f>(&json).unwrap(), subject);
+    }
+}
+
+mod hadamard_product {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_the_components_pairwise() {
+        let subject = Subject::new(1, 2, 3).hadamard_product(&Subject::new(4, 5, 6));
+
+        assert_eq!(subject, Subject::new(4, 10, 18));
+    }
+}
+
+mod hadamard_quotient {
+    use super::*;
+
+    #[test]
+    fn it_divides_the_components_pairwise() {
+        let subject = Vector3f::new(1.0, 6.0, 3.0).hadamard_quotient(&Vector3f::new(2.0, 3.0, -1.0));
+
+        assert_eq!(subject, Vector3f::new(0.5, 2.0, -3.0));
+    }
+}
+
+mod index {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_component_for_the_axis() {
+        let subject = Subject::new(1, 2, 3);
+
+        assert_eq!(subject[0], 1);
+        assert_eq!(subject[1], 2);
+        assert_eq!(subject[2], 3);
+    }
+
+    #[test]
+    fn it_can_mutate_the_component_for_the_axis() {
+        let mut subject = Subject::new(1, 2, 3);
+
+        let axis = subject.max_dimension();
+        subject[axis] = 0;
+
+        assert_eq!(subject, Subject::new(1, 2, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_panics_if_the_axis_is_out_of_range() {
+        let _ = Subject::new(1, 2, 3)[3];
+    }
 }
@@ -0,0 +1,3 @@
+mod surface_interaction;
+
+pub use self::surface_interaction::SurfaceInteraction;
@@ -0,0 +1,74 @@
+use std::ops::Mul;
+use crate::geometry::{Point2f, Point3f, Vector3f, Normal3f, Ray, Transform, Real};
+
+// The local geometry at a ray's hit point. The partial derivatives describe how the
+// surface changes with its (u, v) parameterisation, e.g. for computing texture filters.
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceInteraction {
+    pub p: Point3f,
+    pub p_error: Vector3f,
+    pub time: Real,
+    pub wo: Vector3f,
+    pub n: Normal3f,
+    pub uv: Point2f,
+    pub dpdu: Vector3f,
+    pub dpdv: Vector3f,
+    pub dndu: Normal3f,
+    pub dndv: Normal3f,
+}
+
+impl SurfaceInteraction {
+    // The normal points along dpdu x dpdv unless flip_normal is set, e.g. because the shape's
+    // orientation is reversed or its transform swaps handedness, but not both.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        p: Point3f,
+        p_error: Vector3f,
+        uv: Point2f,
+        wo: Vector3f,
+        dpdu: Vector3f,
+        dpdv: Vector3f,
+        dndu: Normal3f,
+        dndv: Normal3f,
+        time: Real,
+        flip_normal: bool,
+    ) -> Self {
+        let n = Normal3f::from(&dpdu.cross(&dpdv).normalize());
+        let n = if flip_normal { -n } else { n };
+
+        Self { p, p_error, time, wo, n, uv, dpdu, dpdv, dndu, dndv }
+    }
+
+    pub fn spawn_ray(&self, d: &Vector3f) -> Ray {
+        Ray::spawn(&self.p, &self.p_error, &self.n, d, self.time, None)
+    }
+
+    pub fn spawn_ray_to(&self, target: &Point3f) -> Ray {
+        Ray::spawn_to(&self.p, &self.p_error, &self.n, target, self.time, None)
+    }
+}
+
+// Shapes find intersections in object space and use this to move them into world space.
+impl Mul<&SurfaceInteraction> for &Transform {
+    type Output = SurfaceInteraction;
+
+    fn mul(self, si: &SurfaceInteraction) -> Self::Output {
+        let (p, p_error) = self.transform_point_with_abs_error(&si.p, &si.p_error);
+
+        SurfaceInteraction {
+            p,
+            p_error,
+            time: si.time,
+            wo: (self * &si.wo).normalize(),
+            n: (self * &si.n).normalize(),
+            uv: si.uv,
+            dpdu: self * &si.dpdu,
+            dpdv: self * &si.dpdv,
+            dndu: self * &si.dndu,
+            dndv: self * &si.dndv,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
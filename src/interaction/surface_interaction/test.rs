@@ -0,0 +1,103 @@
+use super::*;
+use crate::assert_approx;
+
+type Subject = SurfaceInteraction;
+
+fn subject(flip_normal: bool) -> Subject {
+    Subject::new(
+        Point3f::new(1.0, 2.0, 3.0),
+        Vector3f::new(0.001, 0.001, 0.001),
+        Point2f::new(0.25, 0.5),
+        Vector3f::new(0.0, 0.0, 1.0),
+        Vector3f::new(2.0, 0.0, 0.0),
+        Vector3f::new(0.0, 3.0, 0.0),
+        Normal3f::new(0.0, 0.0, 0.0),
+        Normal3f::new(0.0, 0.0, 0.0),
+        0.5,
+        flip_normal,
+    )
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_sets_the_normal_to_the_normalized_cross_product_of_the_partial_derivatives() {
+        assert_approx!(subject(false).n, Normal3f::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn it_can_flip_the_normal() {
+        assert_approx!(subject(true).n, Normal3f::new(0.0, 0.0, -1.0));
+    }
+}
+
+mod spawn_ray {
+    use super::*;
+
+    #[test]
+    fn it_offsets_the_origin_to_the_side_of_the_surface_the_ray_leaves_from() {
+        let ray = subject(false).spawn_ray(&Vector3f::new(0.0, 0.0, -1.0));
+
+        assert!(ray.o.z() < 3.0 - 0.001);
+        assert_eq!(ray.d, Vector3f::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn it_keeps_the_time_of_the_interaction() {
+        let ray = subject(false).spawn_ray(&Vector3f::new(0.0, 0.0, 1.0));
+
+        assert_eq!(ray.time, 0.5);
+    }
+
+    #[test]
+    fn it_can_spawn_a_ray_that_stops_just_before_a_target() {
+        let ray = subject(false).spawn_ray_to(&Point3f::new(1.0, 2.0, 5.0));
+
+        assert!(ray.at(ray.t_max).z() < 5.0);
+        assert_eq!(ray.time, 0.5);
+    }
+}
+
+mod transform {
+    use super::*;
+
+    #[test]
+    fn it_moves_the_interaction_into_the_space_of_the_transform() {
+        let transform = Transform::translate(&Vector3f::new(1.0, 0.0, 0.0));
+        let si = &transform * &subject(false);
+
+        assert_approx!(si.p, Point3f::new(2.0, 2.0, 3.0));
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, 1.0));
+        assert_eq!(si.uv, Point2f::new(0.25, 0.5));
+        assert_eq!(si.time, 0.5);
+    }
+
+    #[test]
+    fn it_keeps_the_normal_perpendicular_to_the_surface() {
+        let transform = Transform::scale(1.0, 1.0, 4.0);
+        let si = &transform * &Subject::new(
+            Point3f::new(0.0, 0.0, 0.0),
+            Vector3f::new(0.0, 0.0, 0.0),
+            Point2f::new(0.0, 0.0),
+            Vector3f::new(0.0, 0.0, 1.0),
+            Vector3f::new(1.0, 0.0, 1.0),
+            Vector3f::new(0.0, 1.0, 0.0),
+            Normal3f::new(0.0, 0.0, 0.0),
+            Normal3f::new(0.0, 0.0, 0.0),
+            0.0,
+            false,
+        );
+
+        assert_approx!(si.n.dot(&si.dpdu), 0.0, epsilon = 1e-12);
+        assert_approx!(si.n.length(), 1.0);
+    }
+
+    #[test]
+    fn it_grows_the_error_bounds_of_the_point() {
+        let transform = Transform::translate(&Vector3f::new(100.0, 0.0, 0.0));
+        let si = &transform * &subject(false);
+
+        assert!(si.p_error.x() > 0.001);
+    }
+}
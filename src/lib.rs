@@ -0,0 +1,19 @@
+pub mod geometry;
+pub mod interaction;
+pub mod shape;
+
+// A stand-in until participating media are implemented. Rays already carry an optional
+// medium, so the type is exported for callers that need to name it.
+mod dummy;
+
+// The types that most code needs are available from the crate root. The rest, such as
+// the generic types and the floating point helpers, are in the geometry module.
+pub use geometry::{Point2f, Point2i, Point3f, Point3i};
+pub use geometry::{Vector2f, Vector2i, Vector3f, Vector3i};
+pub use geometry::Normal3f;
+pub use geometry::{Ray, RayDifferential};
+pub use geometry::{Bounds2f, Bounds2i, Bounds3f, Bounds3i};
+pub use geometry::{Transform, AnimatedTransform};
+pub use interaction::SurfaceInteraction;
+pub use shape::{Shape, Sphere, Cylinder, Disk, Cone, Paraboloid, Hyperboloid};
+pub use dummy::Medium;
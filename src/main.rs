@@ -1,8 +1,12 @@
-#![feature(optin_builtin_traits)]
-
-mod geometry;
-mod dummy;
+use ray_tracer::{Point3f, Vector3f, Ray, Transform, Shape, Sphere};
 
+// A placeholder until there's a camera and film: traces a single ray at a sphere.
 fn main() {
-    println!("Hello, world!");
+    let sphere = Sphere::new(Transform::translate(&Vector3f::new(0.0, 0.0, 5.0)), false, 1.0, -1.0, 1.0, 360.0);
+    let ray = Ray::new(Point3f::new(0.0, 0.0, 0.0), Vector3f::new(0.0, 0.0, 1.0), None, None, None);
+
+    match sphere.intersect(&ray) {
+        Some((t_hit, si)) => println!("hit at t = {}, p = {:?}, n = {:?}", t_hit, si.p, si.n),
+        None => println!("missed"),
+    }
 }
@@ -0,0 +1,119 @@
+use crate::geometry::{Bounds3f, EFloat, Normal3f, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::geometry::quadratic;
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, axis_normal, azimuth, candidate_hits, normal_derivatives, point_with_error};
+
+// An open cone around the object space z axis with its base at z = 0 and its apex at the
+// given height. Like the other quadrics, it can be swept by less than a full turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cone {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    height: Real,
+    radius: Real,
+    phi_max: Real,
+}
+
+impl Cone {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, height: Real, radius: Real, phi_max: Real) -> Self {
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        Self { object_to_world, reverse_orientation, height, radius, phi_max }
+    }
+
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Vector3f, Real)> {
+        let (ray, o_error, d_error) = self.world_to_object().transform_ray_with_error(ray);
+
+        let o = [0, 1, 2].map(|i| EFloat::new(ray.o[i], o_error[i]));
+        let d = [0, 1, 2].map(|i| EFloat::new(ray.d[i], d_error[i]));
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let k = EFloat::from(self.radius) / EFloat::from(self.height);
+        let k = k * k;
+        let oz = oz - EFloat::from(self.height);
+
+        let a = dx * dx + dy * dy - k * dz * dz;
+        let b = (dx * ox + dy * oy - k * dz * oz) * 2.0;
+        let c = ox * ox + oy * oy - k * oz * oz;
+
+        candidate_hits(&ray, quadratic(a, b, c)?).find_map(|t| {
+            let (p, p_error) = point_with_error(&o, &d, t);
+            let phi = azimuth(&p);
+
+            // The equation also describes the mirror image of the cone above its apex.
+            if p.z() < 0.0 || p.z() > self.height || phi > self.phi_max {
+                None
+            } else {
+                Some((ray, t.value(), p, p_error, phi))
+            }
+        })
+    }
+}
+
+impl Shape for Cone {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.radius, -self.radius, 0.0);
+        let p_max = Point3f::new(self.radius, self.radius, self.height);
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, p_error, phi) = self.intersect_object(ray)?;
+        let (x, y) = (p.x(), p.y());
+
+        let u = phi / self.phi_max;
+        let v = p.z() / self.height;
+
+        // The point is (1 - v) of the way from the apex to the base, so x / (1 - v) is the
+        // x coordinate at the base. Using phi for it avoids dividing by zero at the apex.
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let on_axis = x == 0.0 && y == 0.0;
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+        let dpdv = Vector3f::new(-self.radius * cos_phi, -self.radius * sin_phi, self.height);
+
+        let d2pduu = Vector3f::new(x, y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2pduv = Vector3f::new(sin_phi, -cos_phi, 0.0) * (self.phi_max * self.radius);
+        let d2pdvv = Vector3f::new(0.0, 0.0, 0.0);
+
+        // The apex is a point so dpdu vanishes there and the Weingarten equations break down.
+        let (dndu, dndv) = if on_axis {
+            (Normal3f::new(0.0, 0.0, 0.0), Normal3f::new(0.0, 0.0, 0.0))
+        } else {
+            normal_derivatives(&dpdu, &dpdv, &d2pduu, &d2pduv, &d2pdvv)
+        };
+
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let mut si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        if on_axis {
+            si.n = axis_normal(1.0, flip_normal);
+        }
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    fn area(&self) -> Real {
+        self.radius * (self.height * self.height + self.radius * self.radius).sqrt() * self.phi_max / 2.0
+    }
+}
+
+#[cfg(test)]
+mod test;
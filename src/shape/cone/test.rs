@@ -0,0 +1,170 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::{Float, Normal3f};
+
+type Subject = Cone;
+
+fn cone() -> Subject {
+    Subject::new(Transform::default(), false, 2.0, 1.0, 360.0)
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_cone_in_object_space() {
+        let bounds = cone().object_bound();
+
+        assert_eq!(bounds.p_min, Point3f::new(-1.0, -1.0, 0.0));
+        assert_eq!(bounds.p_max, Point3f::new(1.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn it_bounds_the_cone_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 0.0, -1.0));
+        let bounds = Subject::new(transform, false, 2.0, 1.0, 360.0).world_bound();
+
+        assert_approx!(bounds.p_min, Point3f::new(-1.0, -1.0, -1.0));
+        assert_approx!(bounds.p_max, Point3f::new(1.0, 1.0, 1.0));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_closest_hit() {
+        let (t_hit, si) = cone().intersect(&ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.25, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-0.75, 0.0, 0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = cone().intersect(&ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(-2.0, 0.0, 1.0).normalize(), epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.5, 0.25), epsilon = 1e-5);
+        assert_approx!(si.dpdv, Vector3f::new(1.0, 0.0, 2.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_computes_normal_derivatives_that_are_tangent_to_the_surface() {
+        let (_, si) = cone().intersect(&ray((-5.0, 0.3, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n.dot(si.dndu), 0.0, epsilon = 1e-5);
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_has_a_normal_at_the_apex() {
+        let (t_hit, si) = cone().intersect(&ray((0.0, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(t_hit, 3.0, epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, 1.0));
+        assert_approx!(si.dpdv, Vector3f::new(-1.0, 0.0, 2.0));
+        assert_approx!(si.dndu, Normal3f::new(0.0, 0.0, 0.0));
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_bounds_the_error_of_the_hit_point() {
+        let (_, si) = cone().intersect(&ray((-5.0, 0.3, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        let x = -(0.75 * 0.75 - 0.3 * 0.3 as Real).sqrt();
+
+        assert!((si.p.x() - x).abs() <= si.p_error.x());
+        assert!(si.p_error.x() > 0.0);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(cone().intersect(&ray((-5.0, 2.0, 0.5), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_ignores_the_mirror_image_of_the_cone_above_the_apex() {
+        assert!(cone().intersect(&ray((-5.0, 0.0, 3.0), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_hits_the_cone_with_a_ray_parallel_to_its_side() {
+        let (t_hit, si) = cone().intersect(&ray((-1.0, 0.0, 1.0), (1.0, 0.0, -2.0))).unwrap();
+
+        assert_approx!(t_hit, 0.25, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-0.75, 0.0, 0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_beyond_t_max() {
+        let ray = Ray { t_max: 4.0, ..ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0)) };
+
+        assert!(cone().intersect(&ray).is_none());
+    }
+
+    #[test]
+    fn it_uses_the_far_hit_if_the_near_one_is_outside_the_sweep() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 1.0, 180.0);
+        let (t_hit, si) = subject.intersect(&ray((0.0, -5.0, 0.5), (0.0, 1.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 5.75, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.0, 0.75, 0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, 2.0, 1.0, 360.0);
+        let (_, si) = subject.intersect(&ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(2.0, 0.0, -1.0).normalize(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 0.0, 10.0));
+        let subject = Subject::new(transform, false, 2.0, 1.0, 360.0);
+        let (t_hit, si) = subject.intersect(&ray((-5.0, 0.0, 10.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.25, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-0.75, 0.0, 10.5), epsilon = 1e-5);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 1.0, 270.0);
+
+        let rays = [
+            ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0)),
+            ray((-5.0, 0.0, 3.0), (1.0, 0.0, 0.0)),
+            ray((0.0, -5.0, 0.5), (0.0, 1.0, 0.0)),
+            ray((0.0, 0.0, -5.0), (0.0, 0.0, 1.0)),
+            ray((0.0, 0.0, 0.5), (1.0, 1.0, 0.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(subject.intersect_p(ray), subject.intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_area_of_the_side_of_the_cone() {
+        assert_approx!(cone().area(), Real::PI * (5.0 as Real).sqrt());
+    }
+
+    #[test]
+    fn it_returns_the_area_of_a_partial_cone() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 1.0, 90.0);
+
+        assert_approx!(subject.area(), Real::PI * (5.0 as Real).sqrt() / 4.0);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::geometry::{Bounds3f, EFloat, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::geometry::{gamma, quadratic};
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, azimuth, candidate_hits, normal_derivatives};
+
+// An open cylinder around the object space z axis between two heights. Like the sphere,
+// it can be swept around the axis by less than a full turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylinder {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    radius: Real,
+    z_min: Real,
+    z_max: Real,
+    phi_max: Real,
+}
+
+impl Cylinder {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, radius: Real, z_min: Real, z_max: Real, phi_max: Real) -> Self {
+        let (z_min, z_max) = (z_min.min(z_max), z_min.max(z_max));
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        Self { object_to_world, reverse_orientation, radius, z_min, z_max, phi_max }
+    }
+
+    pub fn radius(&self) -> Real {
+        self.radius
+    }
+
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Real)> {
+        let (ray, o_error, d_error) = self.world_to_object().transform_ray_with_error(ray);
+
+        let ox = EFloat::new(ray.o.x(), o_error.x());
+        let oy = EFloat::new(ray.o.y(), o_error.y());
+        let dx = EFloat::new(ray.d.x(), d_error.x());
+        let dy = EFloat::new(ray.d.y(), d_error.y());
+        let radius = EFloat::from(self.radius);
+
+        let a = dx * dx + dy * dy;
+        let b = (dx * ox + dy * oy) * 2.0;
+        let c = ox * ox + oy * oy - radius * radius;
+
+        // Rays parallel to the axis can't hit the side of the cylinder.
+        if a.value() == 0.0 {
+            return None;
+        }
+
+        let (t_hit, p_hit, phi) = candidate_hits(&ray, quadratic(a, b, c)?)
+            .find_map(|t| self.hit_point(&ray, t.value()).map(|(p, phi)| (t.value(), p, phi)))?;
+
+        Some((ray, t_hit, p_hit, phi))
+    }
+
+    fn hit_point(&self, ray: &Ray, t: Real) -> Option<(Point3f, Real)> {
+        let mut p = ray.at(t);
+
+        // Reprojects the point onto the side of the cylinder to reduce its error.
+        let hit_radius = (p.x() * p.x() + p.y() * p.y()).sqrt();
+        p[0] *= self.radius / hit_radius;
+        p[1] *= self.radius / hit_radius;
+
+        let phi = azimuth(&p);
+
+        if p.z() < self.z_min || p.z() > self.z_max || phi > self.phi_max {
+            None
+        } else {
+            Some((p, phi))
+        }
+    }
+}
+
+impl Shape for Cylinder {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.radius, -self.radius, self.z_min);
+        let p_max = Point3f::new(self.radius, self.radius, self.z_max);
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, phi) = self.intersect_object(ray)?;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+        let height = self.z_max - self.z_min;
+
+        let u = phi / self.phi_max;
+        let v = (z - self.z_min) / height;
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+        let dpdv = Vector3f::new(0.0, 0.0, height);
+
+        // The surface is straight along v so only the derivative along u is non-zero.
+        let d2pduu = Vector3f::new(x, y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2pduv = Vector3f::new(0.0, 0.0, 0.0);
+        let d2pdvv = Vector3f::new(0.0, 0.0, 0.0);
+
+        let (dndu, dndv) = normal_derivatives(&dpdu, &dpdv, &d2pduu, &d2pduv, &d2pdvv);
+
+        let p_error = Vector3f::new(x, y, 0.0).abs() * gamma(3);
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    fn area(&self) -> Real {
+        (self.z_max - self.z_min) * self.radius * self.phi_max
+    }
+}
+
+#[cfg(test)]
+mod test;
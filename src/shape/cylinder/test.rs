@@ -0,0 +1,181 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::{Float, Normal3f};
+
+type Subject = Cylinder;
+
+fn unit_cylinder() -> Subject {
+    Subject::new(Transform::default(), false, 1.0, -1.0, 1.0, 360.0)
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_accepts_the_heights_in_either_order() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 1.0, -1.0, 360.0);
+
+        assert_eq!(subject, unit_cylinder());
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_cylinder_in_object_space() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 0.0, 3.0, 360.0);
+        let bounds = subject.object_bound();
+
+        assert_eq!(bounds.p_min, Point3f::new(-2.0, -2.0, 0.0));
+        assert_eq!(bounds.p_max, Point3f::new(2.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn it_bounds_the_cylinder_in_world_space() {
+        let transform = Transform::rotate_x(90.0);
+        let subject = Subject::new(transform, false, 1.0, 0.0, 3.0, 360.0);
+        let bounds = subject.world_bound();
+
+        assert_approx!(bounds.p_min, Point3f::new(-1.0, -3.0, -1.0), epsilon = 1e-5);
+        assert_approx!(bounds.p_max, Point3f::new(1.0, 0.0, 1.0), epsilon = 1e-5);
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_closest_hit() {
+        let (t_hit, si) = unit_cylinder().intersect(&ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 0.0, 0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = unit_cylinder().intersect(&ray((-5.0, 0.0, 0.5), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.5, 0.75), epsilon = 1e-5);
+        assert_approx!(si.dpdu, Vector3f::new(0.0, -2.0 * Real::PI, 0.0), epsilon = 1e-5);
+        assert_approx!(si.dpdv, Vector3f::new(0.0, 0.0, 2.0));
+    }
+
+    #[test]
+    fn it_computes_how_the_normal_changes_over_the_surface() {
+        let (_, si) = unit_cylinder().intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        // The normal of a unit cylinder follows the point around the axis but not along it.
+        assert_approx!(Vector3f::from(&si.dndu), si.dpdu, epsilon = 1e-5);
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_bounds_the_error_of_the_hit_point() {
+        let (_, si) = unit_cylinder().intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        let x = -(1.0 - 0.3 * 0.3 as Real).sqrt();
+
+        assert!((si.p.x() - x).abs() <= si.p_error.x());
+        assert!(si.p_error.x() > 0.0);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(unit_cylinder().intersect(&ray((-5.0, 2.0, 0.0), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_is_parallel_to_the_axis() {
+        assert!(unit_cylinder().intersect(&ray((0.5, 0.0, -5.0), (0.0, 0.0, 1.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_beyond_t_max() {
+        let ray = Ray { t_max: 3.0, ..ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)) };
+
+        assert!(unit_cylinder().intersect(&ray).is_none());
+    }
+
+    #[test]
+    fn it_ignores_hits_above_or_below_the_cylinder() {
+        assert!(unit_cylinder().intersect(&ray((-5.0, 0.0, 1.5), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_hits_the_inside_through_the_open_end() {
+        let (t_hit, si) = unit_cylinder().intersect(&ray((0.0, 0.0, 2.0), (1.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(t_hit, 1.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(1.0, 0.0, 1.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_uses_the_far_hit_if_the_near_one_is_outside_the_sweep() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -1.0, 1.0, 180.0);
+        let (t_hit, si) = subject.intersect(&ray((0.0, -5.0, 0.0), (0.0, 1.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 6.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.0, 1.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, 1.0, -1.0, 1.0, 360.0);
+        let (_, si) = subject.intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 10.0, 0.0));
+        let subject = Subject::new(transform, false, 1.0, -1.0, 1.0, 360.0);
+        let (t_hit, si) = subject.intersect(&ray((-5.0, 10.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 10.0, 0.0), epsilon = 1e-5);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -0.5, 0.5, 270.0);
+
+        let rays = [
+            ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+            ray((-5.0, 0.0, 0.8), (1.0, 0.0, 0.0)),
+            ray((0.0, -5.0, 0.0), (0.0, 1.0, 0.0)),
+            ray((5.0, -0.5, 0.0), (-1.0, 0.0, 0.0)),
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(subject.intersect_p(ray), subject.intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_area_of_the_side_of_the_cylinder() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 0.0, 3.0, 360.0);
+
+        assert_approx!(subject.area(), 12.0 * Real::PI);
+    }
+
+    #[test]
+    fn it_returns_the_area_of_a_partial_cylinder() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.0, 1.0, 90.0);
+
+        assert_approx!(subject.area(), 0.5 * Real::PI);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::geometry::{Bounds3f, Normal3f, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, axis_normal, azimuth};
+
+// A flat disk facing along the object space z axis at the given height. A non-zero inner
+// radius cuts a hole in the middle to make an annulus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Disk {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    height: Real,
+    radius: Real,
+    inner_radius: Real,
+    phi_max: Real,
+}
+
+impl Disk {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, height: Real, radius: Real, inner_radius: Real, phi_max: Real) -> Self {
+        // max and min rather than clamp, which panics if the radius is NaN.
+        let radius = radius.abs();
+        let inner_radius = inner_radius.max(0.0).min(radius);
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        Self { object_to_world, reverse_orientation, height, radius, inner_radius, phi_max }
+    }
+
+    pub fn radius(&self) -> Real {
+        self.radius
+    }
+
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Real)> {
+        let (ray, _, _) = self.world_to_object().transform_ray_with_error(ray);
+
+        // Rays parallel to the disk can't hit it.
+        if ray.d.z() == 0.0 {
+            return None;
+        }
+
+        let t_hit = (self.height - ray.o.z()) / ray.d.z();
+
+        if t_hit <= 0.0 || t_hit >= ray.t_max {
+            return None;
+        }
+
+        let mut p = ray.at(t_hit);
+        let distance_squared = p.x() * p.x() + p.y() * p.y();
+
+        if distance_squared > self.radius * self.radius || distance_squared < self.inner_radius * self.inner_radius {
+            return None;
+        }
+
+        let phi = azimuth(&p);
+
+        if phi > self.phi_max {
+            return None;
+        }
+
+        // Puts the point exactly on the plane of the disk so it has no error.
+        p[2] = self.height;
+
+        Some((ray, t_hit, p, phi))
+    }
+}
+
+impl Shape for Disk {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.radius, -self.radius, self.height);
+        let p_max = Point3f::new(self.radius, self.radius, self.height);
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, phi) = self.intersect_object(ray)?;
+        let (x, y) = (p.x(), p.y());
+        let hit_radius = (x * x + y * y).sqrt();
+
+        let u = phi / self.phi_max;
+        let v = (self.radius - hit_radius) / (self.radius - self.inner_radius);
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+
+        // Every u maps to the centre so any radial direction will do for dpdv there.
+        let dpdv = if hit_radius > 0.0 {
+            Vector3f::new(x, y, 0.0) * ((self.inner_radius - self.radius) / hit_radius)
+        } else {
+            Vector3f::new(self.inner_radius - self.radius, 0.0, 0.0)
+        };
+
+        // The disk is flat so the normal is the same everywhere.
+        let dndu = Normal3f::new(0.0, 0.0, 0.0);
+        let dndv = Normal3f::new(0.0, 0.0, 0.0);
+
+        let p_error = Vector3f::new(0.0, 0.0, 0.0);
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let mut si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        if hit_radius == 0.0 {
+            si.n = axis_normal(1.0, flip_normal);
+        }
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    fn area(&self) -> Real {
+        self.phi_max * 0.5 * (self.radius * self.radius - self.inner_radius * self.inner_radius)
+    }
+}
+
+#[cfg(test)]
+mod test;
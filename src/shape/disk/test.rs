@@ -0,0 +1,208 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::{Float, gamma};
+
+type Subject = Disk;
+
+fn unit_disk() -> Subject {
+    Subject::new(Transform::default(), false, 0.0, 1.0, 0.0, 360.0)
+}
+
+fn annulus() -> Subject {
+    Subject::new(Transform::default(), false, 0.0, 1.0, 0.5, 360.0)
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_clamps_the_inner_radius_to_the_radius() {
+        let subject = Subject::new(Transform::default(), false, 0.0, 1.0, 2.0, 360.0);
+
+        assert_eq!(subject.area(), 0.0);
+    }
+
+    #[test]
+    fn it_uses_the_size_of_a_negative_radius() {
+        let subject = Subject::new(Transform::default(), false, 0.0, -1.0, 0.0, 360.0);
+
+        assert_eq!(subject, unit_disk());
+    }
+
+    #[test]
+    fn it_does_not_panic_if_the_radius_is_nan() {
+        let subject = Subject::new(Transform::default(), false, 0.0, Real::NAN, 0.5, 360.0);
+
+        assert!(subject.radius().is_nan());
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_disk_in_object_space() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 3.0, 0.0, 360.0);
+        let bounds = subject.object_bound();
+
+        assert_eq!(bounds.p_min, Point3f::new(-3.0, -3.0, 2.0));
+        assert_eq!(bounds.p_max, Point3f::new(3.0, 3.0, 2.0));
+    }
+
+    #[test]
+    fn it_bounds_the_disk_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(1.0, 0.0, 0.0));
+        let bounds = Subject::new(transform, false, 0.0, 1.0, 0.0, 360.0).world_bound();
+
+        assert_approx!(bounds.p_min, Point3f::new(0.0, -1.0, 0.0));
+        assert_approx!(bounds.p_max, Point3f::new(2.0, 1.0, 0.0));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_hit() {
+        let (t_hit, si) = unit_disk().intersect(&ray((0.5, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(t_hit, 5.0);
+        assert_approx!(si.p, Point3f::new(0.5, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = unit_disk().intersect(&ray((0.0, 0.5, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, 1.0));
+        assert_approx!(si.uv, Point2f::new(0.25, 0.5));
+        assert_approx!(si.dpdu, Vector3f::new(-Real::PI, 0.0, 0.0));
+        assert_approx!(si.dpdv, Vector3f::new(0.0, -1.0, 0.0));
+        assert_approx!(si.dndu, Normal3f::new(0.0, 0.0, 0.0));
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_has_a_normal_at_the_centre_of_the_disk() {
+        let (_, si) = unit_disk().intersect(&ray((0.0, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, 1.0));
+        assert_approx!(si.uv, Point2f::new(0.0, 1.0));
+        assert_approx!(si.dpdv, Vector3f::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_flips_the_normal_at_the_centre_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, 0.0, 1.0, 0.0, 360.0);
+        let (_, si) = subject.intersect(&ray((0.0, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn it_puts_the_hit_point_exactly_on_the_disk() {
+        let subject = Subject::new(Transform::default(), false, 0.1, 1.0, 0.0, 360.0);
+        let (_, si) = subject.intersect(&ray((0.3, 0.2, 0.7), (0.1, 0.2, -0.3))).unwrap();
+
+        // The only error left is from moving the point into world space.
+        assert_eq!(si.p.z(), 0.1);
+        assert!(si.p_error.z() <= gamma(3) * 0.1);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(unit_disk().intersect(&ray((2.0, 0.0, 5.0), (0.0, 0.0, -1.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_is_parallel_to_the_disk() {
+        assert!(unit_disk().intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_disk_is_behind_the_ray() {
+        assert!(unit_disk().intersect(&ray((0.5, 0.0, 5.0), (0.0, 0.0, 1.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_beyond_t_max() {
+        let ray = Ray { t_max: 4.0, ..ray((0.5, 0.0, 5.0), (0.0, 0.0, -1.0)) };
+
+        assert!(unit_disk().intersect(&ray).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_passes_through_the_hole() {
+        assert!(annulus().intersect(&ray((0.25, 0.0, 5.0), (0.0, 0.0, -1.0))).is_none());
+        assert!(annulus().intersect(&ray((0.75, 0.0, 5.0), (0.0, 0.0, -1.0))).is_some());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_outside_the_sweep() {
+        let subject = Subject::new(Transform::default(), false, 0.0, 1.0, 0.0, 90.0);
+
+        assert!(subject.intersect(&ray((0.5, 0.5, 5.0), (0.0, 0.0, -1.0))).is_some());
+        assert!(subject.intersect(&ray((-0.5, 0.5, 5.0), (0.0, 0.0, -1.0))).is_none());
+    }
+
+    #[test]
+    fn it_parameterises_v_from_the_outer_to_the_inner_radius() {
+        let (_, si) = annulus().intersect(&ray((-0.75, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(si.uv, Point2f::new(0.5, 0.5));
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, 0.0, 1.0, 0.0, 360.0);
+        let (_, si) = subject.intersect(&ray((0.5, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::rotate_y(90.0);
+        let subject = Subject::new(transform, false, 0.0, 1.0, 0.0, 360.0);
+        let (t_hit, si) = subject.intersect(&ray((5.0, 0.0, 0.5), (-1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 5.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.0, 0.0, 0.5), epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let rays = [
+            ray((0.75, 0.0, 5.0), (0.0, 0.0, -1.0)),
+            ray((0.25, 0.0, 5.0), (0.0, 0.0, -1.0)),
+            ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+            ray((0.0, 0.75, -1.0), (0.0, 0.0, 1.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(annulus().intersect_p(ray), annulus().intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_area_of_the_disk() {
+        assert_approx!(Subject::new(Transform::default(), false, 0.0, 2.0, 0.0, 360.0).area(), 4.0 * Real::PI);
+    }
+
+    #[test]
+    fn it_returns_the_area_of_a_partial_annulus() {
+        let subject = Subject::new(Transform::default(), false, 0.0, 2.0, 1.0, 180.0);
+
+        assert_approx!(subject.area(), 1.5 * Real::PI);
+    }
+}
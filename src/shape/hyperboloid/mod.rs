@@ -0,0 +1,197 @@
+use std::fmt;
+use std::error::Error;
+use crate::geometry::{Bounds3f, EFloat, Float, Normal3f, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::geometry::quadratic;
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, axis_normal, candidate_hits, normal_derivatives, point_with_error};
+
+// The surface swept by rotating the line from p1 to p2 around the object space z axis, e.g. a
+// cooling tower if the line is skew to the axis. The points must be at different heights.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hyperboloid {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    p1: Point3f,
+    p2: Point3f,
+    r_max: Real,
+    phi_max: Real,
+    // The squared distance from the axis as a quadratic in z, i.e. ah * z^2 + bh * z + ch.
+    ah: Real,
+    bh: Real,
+    ch: Real,
+}
+
+// Returned if both points are at the same height. The line would sweep out an annulus, which
+// should be a Disk instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlatHyperboloid;
+
+impl fmt::Display for FlatHyperboloid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the points of a hyperboloid must be at different heights")
+    }
+}
+
+impl Error for FlatHyperboloid { }
+
+impl Hyperboloid {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, p1: Point3f, p2: Point3f, phi_max: Real) -> Result<Self, FlatHyperboloid> {
+        if p1.z() == p2.z() {
+            return Err(FlatHyperboloid);
+        }
+
+        let (p1, p2) = if p1.z() > p2.z() { (p2, p1) } else { (p1, p2) };
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        let radius_squared = |p: &Point3f| p.x() * p.x() + p.y() * p.y();
+        let r_max = radius_squared(&p1).max(radius_squared(&p2)).sqrt();
+
+        // Substitutes v = (z - p1.z) / d.z into the squared distance of p1 + v * d from the axis.
+        let d = p2 - p1;
+        let s = p1.x() * d.x() + p1.y() * d.y();
+        let d_xy = d.x() * d.x() + d.y() * d.y();
+        let (z1, dz) = (p1.z(), d.z());
+
+        let ah = d_xy / (dz * dz);
+        let bh = 2.0 * s / dz - 2.0 * d_xy * z1 / (dz * dz);
+        let ch = radius_squared(&p1) - 2.0 * s * z1 / dz + d_xy * z1 * z1 / (dz * dz);
+
+        Ok(Self { object_to_world, reverse_orientation, p1, p2, r_max, phi_max, ah, bh, ch })
+    }
+
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Vector3f, Real)> {
+        let (ray, o_error, d_error) = self.world_to_object().transform_ray_with_error(ray);
+
+        let o = [0, 1, 2].map(|i| EFloat::new(ray.o[i], o_error[i]));
+        let d = [0, 1, 2].map(|i| EFloat::new(ray.d[i], d_error[i]));
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let (ah, bh, ch) = (EFloat::from(self.ah), EFloat::from(self.bh), EFloat::from(self.ch));
+
+        let a = dx * dx + dy * dy - ah * dz * dz;
+        let b = (dx * ox + dy * oy - ah * dz * oz) * 2.0 - bh * dz;
+        let c = ox * ox + oy * oy - ah * oz * oz - bh * oz - ch;
+
+        candidate_hits(&ray, quadratic(a, b, c)?).find_map(|t| {
+            let (p, p_error) = point_with_error(&o, &d, t);
+            let phi = self.phi(&p);
+
+            if p.z() < self.p1.z() || p.z() > self.p2.z() || phi > self.phi_max {
+                None
+            } else {
+                Some((ray, t.value(), p, p_error, phi))
+            }
+        })
+    }
+
+    // The angle the line has been rotated by to reach the point, rather than the point's
+    // angle around the axis, since the line itself may not start at phi = 0.
+    fn phi(&self, p: &Point3f) -> Real {
+        let v = (p.z() - self.p1.z()) / (self.p2.z() - self.p1.z());
+        let pr = self.p1.lerp(&self.p2, v);
+
+        let phi = (pr.x() * p.y() - p.x() * pr.y()).atan2(p.x() * pr.x() + p.y() * pr.y());
+
+        if phi < 0.0 { phi + 2.0 * Real::PI } else { phi }
+    }
+}
+
+impl Shape for Hyperboloid {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.r_max, -self.r_max, self.p1.z());
+        let p_max = Point3f::new(self.r_max, self.r_max, self.p2.z());
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, p_error, phi) = self.intersect_object(ray)?;
+        let (x, y) = (p.x(), p.y());
+        let d = self.p2 - self.p1;
+
+        let u = phi / self.phi_max;
+        let v = (p.z() - self.p1.z()) / d.z();
+
+        let (sin_phi, cos_phi) = (phi.sin(), phi.cos());
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+        let dpdv = Vector3f::new(d.x() * cos_phi - d.y() * sin_phi, d.x() * sin_phi + d.y() * cos_phi, d.z());
+
+        // The surface is ruled so it's straight along v.
+        let d2pduu = Vector3f::new(x, y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2pduv = Vector3f::new(-dpdv.y(), dpdv.x(), 0.0) * self.phi_max;
+        let d2pdvv = Vector3f::new(0.0, 0.0, 0.0);
+
+        // dpdu vanishes where the line crosses the axis, like at the apex of a cone.
+        let on_axis = x == 0.0 && y == 0.0;
+
+        let (dndu, dndv) = if on_axis {
+            (Normal3f::new(0.0, 0.0, 0.0), Normal3f::new(0.0, 0.0, 0.0))
+        } else {
+            normal_derivatives(&dpdu, &dpdv, &d2pduu, &d2pduv, &d2pdvv)
+        };
+
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let mut si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        // Near the top of the line it points up like a cone's apex and near the bottom it
+        // points down like a paraboloid's vertex.
+        if on_axis {
+            si.n = axis_normal(if v > 0.5 { 1.0 } else { -1.0 }, flip_normal);
+        }
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    // Integrates |dpdu x dpdv| over the surface. It doesn't depend on u, and squared it's a
+    // quadratic in v, so the integral of its square root along the line has a closed form.
+    fn area(&self) -> Real {
+        let d = self.p2 - self.p1;
+        let s = self.p1.x() * d.x() + self.p1.y() * d.y();
+        let d_xy = d.x() * d.x() + d.y() * d.y();
+        let r1 = self.p1.x() * self.p1.x() + self.p1.y() * self.p1.y();
+        let dz2 = d.z() * d.z();
+
+        let a = d_xy * (dz2 + d_xy);
+        let b = 2.0 * s * (dz2 + d_xy);
+        let c = dz2 * r1 + s * s;
+
+        // The line is parallel to the axis, so the surface is part of a cylinder.
+        if a == 0.0 {
+            return self.phi_max * c.sqrt();
+        }
+
+        let k = 4.0 * a * c - b * b;
+
+        let integral = |v: Real| {
+            let root = (a * v * v + b * v + c).sqrt();
+            let linear = (2.0 * a * v + b) * root / (4.0 * a);
+
+            match k > 0.0 {
+                true => linear + k / (8.0 * a * a.sqrt()) * (2.0 * a * v + b + 2.0 * a.sqrt() * root).ln(),
+                false => linear,
+            }
+        };
+
+        self.phi_max * (integral(1.0) - integral(0.0))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,209 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::Normal3f;
+
+type Subject = Hyperboloid;
+
+// The hyperboloid x^2 + y^2 - z^2 = 1 between z = -1 and z = 1.
+fn hyperboloid() -> Subject {
+    Subject::new(Transform::default(), false, Point3f::new(1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0), 360.0).unwrap()
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_accepts_the_points_in_either_order() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, 1.0, 1.0), Point3f::new(1.0, -1.0, -1.0), 360.0).unwrap();
+
+        assert_eq!(subject, hyperboloid());
+    }
+
+    #[test]
+    fn it_returns_an_error_if_the_points_are_at_the_same_height() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, 0.0, 1.0), Point3f::new(2.0, 0.0, 1.0), 360.0);
+
+        assert_eq!(subject, Err(FlatHyperboloid));
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_hyperboloid_in_object_space() {
+        let bounds = hyperboloid().object_bound();
+        let r_max = (2.0 as Real).sqrt();
+
+        assert_eq!(bounds.p_min, Point3f::new(-r_max, -r_max, -1.0));
+        assert_eq!(bounds.p_max, Point3f::new(r_max, r_max, 1.0));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_closest_hit() {
+        let (t_hit, si) = hyperboloid().intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = hyperboloid().intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.5, 0.5), epsilon = 1e-5);
+        assert_approx!(si.dpdv, Vector3f::new(0.0, -2.0, 2.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_computes_normal_derivatives_that_are_tangent_to_the_surface() {
+        let (_, si) = hyperboloid().intersect(&ray((-5.0, 0.3, 0.4), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n.dot(si.dndu), 0.0, epsilon = 1e-5);
+        assert_approx!(si.n.dot(si.dndv), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_hits_the_curved_part_of_the_surface() {
+        let (t_hit, si) = hyperboloid().intersect(&ray((1.2, 0.0, -5.0), (0.0, 0.0, 1.0))).unwrap();
+        let z = -(1.2 * 1.2 - 1.0 as Real).sqrt();
+
+        assert_approx!(t_hit, 5.0 + z, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(1.2, 0.0, z), epsilon = 1e-5);
+        assert!((si.p.z() - z).abs() <= si.p_error.z());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_passes_through_the_middle() {
+        assert!(hyperboloid().intersect(&ray((0.5, 0.0, -5.0), (0.0, 0.0, 1.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(hyperboloid().intersect(&ray((-5.0, 0.0, 2.0), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_measures_phi_from_the_line_rather_than_the_x_axis() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0), 90.0).unwrap();
+
+        // At z = 0.5 the line is about 27 degrees around from the x axis.
+        let direction = |degrees: Real| (degrees.to_radians().cos(), degrees.to_radians().sin(), 0.0);
+
+        assert!(subject.intersect(&ray((0.0, 0.0, 0.5), direction(100.0))).is_some());
+        assert!(subject.intersect(&ray((0.0, 0.0, 0.5), direction(10.0))).is_none());
+    }
+
+    #[test]
+    fn it_handles_lines_that_are_closest_to_the_axis_away_from_the_origin() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, -1.0, 0.0), Point3f::new(1.0, 1.0, 2.0), 360.0).unwrap();
+
+        let (t_hit, si) = subject.intersect(&ray((-5.0, 0.0, 1.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 0.0, 1.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_behaves_like_a_cylinder_if_the_line_is_parallel_to_the_axis() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, 0.0, 0.0), Point3f::new(1.0, 0.0, 1.0), 360.0).unwrap();
+
+        let (t_hit, si) = subject.intersect(&ray((0.0, -5.0, 0.5), (0.0, 1.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.75, 0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_has_a_normal_where_the_line_meets_the_axis_at_the_top() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, 0.0, 0.0), Point3f::new(0.0, 0.0, 2.0), 360.0).unwrap();
+        let (t_hit, si) = subject.intersect(&ray((0.0, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(t_hit, 3.0, epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, 1.0));
+        assert_approx!(si.dndu, Normal3f::new(0.0, 0.0, 0.0));
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_has_a_normal_where_the_line_meets_the_axis_at_the_bottom() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(0.0, 0.0, 0.0), Point3f::new(1.0, 0.0, 2.0), 360.0).unwrap();
+        let (t_hit, si) = subject.intersect(&ray((0.0, 0.0, -5.0), (0.0, 0.0, 1.0))).unwrap();
+
+        assert_approx!(t_hit, 5.0, epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, Point3f::new(1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0), 360.0).unwrap();
+        let (_, si) = subject.intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 10.0, 0.0));
+        let subject = Subject::new(transform, false, Point3f::new(1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0), 360.0).unwrap();
+        let (t_hit, si) = subject.intersect(&ray((-5.0, 10.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 10.0, 0.0), epsilon = 1e-5);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, -1.0, -1.0), Point3f::new(1.0, 1.0, 1.0), 270.0).unwrap();
+
+        let rays = [
+            ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+            ray((1.2, 0.0, -5.0), (0.0, 0.0, 1.0)),
+            ray((0.5, 0.0, -5.0), (0.0, 0.0, 1.0)),
+            ray((0.0, -5.0, 0.0), (0.0, 1.0, 0.0)),
+            ray((0.0, 0.0, 0.0), (1.0, 1.0, 0.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(subject.intersect_p(ray), subject.intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+    use crate::geometry::Float;
+
+    #[test]
+    fn it_returns_the_surface_area_of_the_hyperboloid() {
+        // 2pi times the integral of sqrt(1 + 2z^2) from -1 to 1.
+        let sqrt2 = (2.0 as Real).sqrt();
+        let expected = 4.0 * Real::PI * ((3.0 as Real).sqrt() / 2.0 + sqrt2.asinh() / (2.0 * sqrt2));
+
+        assert_approx!(hyperboloid().area(), expected);
+    }
+
+    #[test]
+    fn it_returns_the_area_of_a_cylinder_if_the_line_is_parallel_to_the_axis() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(0.0, 2.0, 0.0), Point3f::new(0.0, 2.0, 3.0), 180.0).unwrap();
+
+        assert_approx!(subject.area(), 6.0 * Real::PI);
+    }
+
+    #[test]
+    fn it_returns_the_area_of_a_cone_if_the_line_meets_the_axis() {
+        let subject = Subject::new(Transform::default(), false, Point3f::new(1.0, 0.0, 0.0), Point3f::new(0.0, 0.0, 2.0), 360.0).unwrap();
+
+        assert_approx!(subject.area(), Real::PI * (5.0 as Real).sqrt());
+    }
+}
@@ -0,0 +1,115 @@
+use crate::geometry::{Bounds3f, EFloat, Float, Normal3f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::interaction::SurfaceInteraction;
+
+mod sphere;
+mod cylinder;
+mod disk;
+mod cone;
+mod paraboloid;
+mod hyperboloid;
+
+pub use self::sphere::Sphere;
+pub use self::cylinder::Cylinder;
+pub use self::disk::Disk;
+pub use self::cone::Cone;
+pub use self::paraboloid::Paraboloid;
+pub use self::hyperboloid::{Hyperboloid, FlatHyperboloid};
+
+// A surface that rays can be intersected with. Shapes are defined in their own object space
+// and placed in the scene by their object_to_world transform.
+pub trait Shape {
+    fn object_to_world(&self) -> &Transform;
+    fn reverse_orientation(&self) -> bool;
+
+    fn object_bound(&self) -> Bounds3f;
+
+    // Returns the distance along the ray to the closest hit within its t_max, along with the
+    // local geometry at that point in world space.
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)>;
+
+    fn area(&self) -> Real;
+
+    fn world_to_object(&self) -> Transform {
+        self.object_to_world().inverse()
+    }
+
+    fn transform_swaps_handedness(&self) -> bool {
+        self.object_to_world().swaps_handedness()
+    }
+
+    fn world_bound(&self) -> Bounds3f {
+        self.object_to_world() * &self.object_bound()
+    }
+
+    // Shapes should override this if they can skip computing the surface interaction.
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect(ray).is_some()
+    }
+}
+
+// Computes dndu and dndv from the first and second partial derivatives of the surface
+// using the Weingarten equations.
+fn normal_derivatives(
+    dpdu: &Vector3f,
+    dpdv: &Vector3f,
+    d2pduu: &Vector3f,
+    d2pduv: &Vector3f,
+    d2pdvv: &Vector3f,
+) -> (Normal3f, Normal3f) {
+    let e1 = dpdu.dot(dpdu);
+    let f1 = dpdu.dot(dpdv);
+    let g1 = dpdv.dot(dpdv);
+
+    let n = dpdu.cross(dpdv).normalize();
+    let e2 = n.dot(d2pduu);
+    let f2 = n.dot(d2pduv);
+    let g2 = n.dot(d2pdvv);
+
+    let inv_egf2 = (e1 * g1 - f1 * f1).recip();
+
+    let dndu = dpdu * ((f2 * f1 - e2 * g1) * inv_egf2) + dpdv * ((e2 * f1 - f2 * e1) * inv_egf2);
+    let dndv = dpdu * ((g2 * f1 - f2 * g1) * inv_egf2) + dpdv * ((f2 * f1 - g2 * e1) * inv_egf2);
+
+    (Normal3f::from(&dndu), Normal3f::from(&dndv))
+}
+
+// Where a surface pinches to a point on the z axis, e.g. the apex of a cone, dpdu vanishes
+// and the normal can't come from dpdu x dpdv. It points along the axis instead.
+fn axis_normal(z: Real, flip_normal: bool) -> Normal3f {
+    let n = Normal3f::new(0.0, 0.0, z);
+
+    if flip_normal { -n } else { n }
+}
+
+// Returns the angle of the point around the z axis in the range [0, 2pi).
+fn azimuth(p: &Point3f) -> Real {
+    let phi = p.y().atan2(p.x());
+
+    if phi < 0.0 { phi + 2.0 * Real::PI } else { phi }
+}
+
+// Returns the roots of a quadric's equation that are certainly in front of the ray and within
+// its t_max, closest first. Roots with unbounded error, e.g. from a ray parallel to the
+// surface, are skipped since the hit could be anywhere.
+fn candidate_hits(ray: &Ray, roots: (EFloat, EFloat)) -> impl Iterator<Item=EFloat> {
+    let t_max = ray.t_max;
+
+    IntoIterator::into_iter([roots.0, roots.1])
+        .filter(move |t| t.lower_bound() > 0.0 && t.upper_bound() <= t_max && t.upper_bound().is_finite())
+}
+
+// Evaluates o + t * d with intervals, returning the point and a bound on its error.
+fn point_with_error(o: &[EFloat; 3], d: &[EFloat; 3], t: EFloat) -> (Point3f, Vector3f) {
+    let p = [o[0] + t * d[0], o[1] + t * d[1], o[2] + t * d[2]];
+
+    let point = Point3f::generate(|i| p[i].value());
+    let error = Vector3f::generate(|i| p[i].absolute_error());
+
+    (point, error)
+}
+
+// Shared by the shape tests, which build many rays from plain tuples.
+#[cfg(test)]
+fn ray(o: (Real, Real, Real), d: (Real, Real, Real)) -> Ray {
+    Ray::new(Point3f::new(o.0, o.1, o.2), Vector3f::new(d.0, d.1, d.2), None, None, None)
+}
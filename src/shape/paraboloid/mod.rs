@@ -0,0 +1,128 @@
+use crate::geometry::{Bounds3f, EFloat, Normal3f, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::geometry::quadratic;
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, axis_normal, azimuth, candidate_hits, normal_derivatives, point_with_error};
+
+// A paraboloid around the object space z axis with its vertex at the origin, opening upwards
+// to the given radius at z_max. It can be clipped to start higher up at z_min.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paraboloid {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    radius: Real,
+    z_min: Real,
+    z_max: Real,
+    phi_max: Real,
+}
+
+impl Paraboloid {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, radius: Real, z_min: Real, z_max: Real, phi_max: Real) -> Self {
+        let (z_min, z_max) = (z_min.min(z_max), z_min.max(z_max));
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        Self { object_to_world, reverse_orientation, radius, z_min, z_max, phi_max }
+    }
+
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Vector3f, Real)> {
+        let (ray, o_error, d_error) = self.world_to_object().transform_ray_with_error(ray);
+
+        let o = [0, 1, 2].map(|i| EFloat::new(ray.o[i], o_error[i]));
+        let d = [0, 1, 2].map(|i| EFloat::new(ray.d[i], d_error[i]));
+        let [ox, oy, oz] = o;
+        let [dx, dy, dz] = d;
+
+        let radius = EFloat::from(self.radius);
+        let k = EFloat::from(self.z_max) / (radius * radius);
+
+        let a = k * (dx * dx + dy * dy);
+        let b = k * (dx * ox + dy * oy) * 2.0 - dz;
+        let c = k * (ox * ox + oy * oy) - oz;
+
+        candidate_hits(&ray, quadratic(a, b, c)?).find_map(|t| {
+            let (p, p_error) = point_with_error(&o, &d, t);
+            let phi = azimuth(&p);
+
+            if p.z() < self.z_min || p.z() > self.z_max || phi > self.phi_max {
+                None
+            } else {
+                Some((ray, t.value(), p, p_error, phi))
+            }
+        })
+    }
+}
+
+impl Shape for Paraboloid {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.radius, -self.radius, self.z_min);
+        let p_max = Point3f::new(self.radius, self.radius, self.z_max);
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, p_error, phi) = self.intersect_object(ray)?;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+        let height = self.z_max - self.z_min;
+
+        let u = phi / self.phi_max;
+        let v = (z - self.z_min) / height;
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+
+        // At the vertex dpdu vanishes and dpdv grows without bound as it turns radial, so
+        // only the direction of dpdv is kept and the normal derivatives are left at zero.
+        let on_axis = x == 0.0 && y == 0.0;
+
+        let (dpdv, dndu, dndv) = if on_axis {
+            let (sin_phi, cos_phi) = phi.sin_cos();
+
+            (Vector3f::new(cos_phi, sin_phi, 0.0), Normal3f::new(0.0, 0.0, 0.0), Normal3f::new(0.0, 0.0, 0.0))
+        } else {
+            let dpdv = Vector3f::new(x / (2.0 * z), y / (2.0 * z), 1.0) * height;
+
+            let d2pduu = Vector3f::new(x, y, 0.0) * (-self.phi_max * self.phi_max);
+            let d2pduv = Vector3f::new(-y / (2.0 * z), x / (2.0 * z), 0.0) * (height * self.phi_max);
+            let d2pdvv = Vector3f::new(x / (4.0 * z * z), y / (4.0 * z * z), 0.0) * (-height * height);
+
+            let (dndu, dndv) = normal_derivatives(&dpdu, &dpdv, &d2pduu, &d2pduv, &d2pdvv);
+
+            (dpdv, dndu, dndv)
+        };
+
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let mut si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        if on_axis {
+            si.n = axis_normal(-1.0, flip_normal);
+        }
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    fn area(&self) -> Real {
+        let radius2 = self.radius * self.radius;
+        let k = 4.0 * self.z_max / radius2;
+
+        let ring = |z: Real| (k * z + 1.0).powf(1.5);
+
+        (radius2 * radius2 * self.phi_max / (12.0 * self.z_max * self.z_max)) * (ring(self.z_max) - ring(self.z_min))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,161 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::{Float, Normal3f};
+
+type Subject = Paraboloid;
+
+fn paraboloid() -> Subject {
+    Subject::new(Transform::default(), false, 1.0, 0.0, 1.0, 360.0)
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_accepts_the_heights_in_either_order() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 1.0, 0.0, 360.0);
+
+        assert_eq!(subject, paraboloid());
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_paraboloid_in_object_space() {
+        let subject = Subject::new(Transform::default(), false, 2.0, 0.5, 3.0, 360.0);
+        let bounds = subject.object_bound();
+
+        assert_eq!(bounds.p_min, Point3f::new(-2.0, -2.0, 0.5));
+        assert_eq!(bounds.p_max, Point3f::new(2.0, 2.0, 3.0));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_closest_hit() {
+        let (t_hit, si) = paraboloid().intersect(&ray((-5.0, 0.0, 0.25), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.5, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-0.5, 0.0, 0.25), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = paraboloid().intersect(&ray((-5.0, 0.0, 0.25), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(-1.0, 0.0, -1.0).normalize(), epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.5, 0.25), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_computes_normal_derivatives_that_are_tangent_to_the_surface() {
+        let (_, si) = paraboloid().intersect(&ray((-5.0, 0.3, 0.25), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n.dot(si.dndu), 0.0, epsilon = 1e-5);
+        assert_approx!(si.n.dot(si.dndv), 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_has_a_normal_at_the_vertex() {
+        let (t_hit, si) = paraboloid().intersect(&ray((0.0, 0.0, -5.0), (0.0, 0.0, 1.0))).unwrap();
+
+        assert_approx!(t_hit, 5.0, epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(0.0, 0.0, -1.0));
+        assert_approx!(si.dpdv, Vector3f::new(1.0, 0.0, 0.0));
+        assert_approx!(si.dndu, Normal3f::new(0.0, 0.0, 0.0));
+        assert_approx!(si.dndv, Normal3f::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_hits_the_inside_from_above() {
+        let (t_hit, si) = paraboloid().intersect(&ray((0.3, 0.0, 5.0), (0.0, 0.0, -1.0))).unwrap();
+
+        assert_approx!(t_hit, 4.91, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.3, 0.0, 0.09), epsilon = 1e-5);
+        assert!(si.p_error.z() > 0.0);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(paraboloid().intersect(&ray((-5.0, 0.0, -0.5), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_ignores_hits_that_are_clipped_by_the_heights() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.5, 1.0, 360.0);
+
+        assert!(subject.intersect(&ray((0.3, 0.0, 5.0), (0.0, 0.0, -1.0))).is_none());
+        assert!(subject.intersect(&ray((-5.0, 0.0, 0.6), (1.0, 0.0, 0.0))).is_some());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_outside_the_sweep() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.0, 1.0, 90.0);
+
+        assert!(subject.intersect(&ray((0.3, 0.3, 5.0), (0.0, 0.0, -1.0))).is_some());
+        assert!(subject.intersect(&ray((-0.3, 0.3, 5.0), (0.0, 0.0, -1.0))).is_none());
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::new(Transform::default(), true, 1.0, 0.0, 1.0, 360.0);
+        let (_, si) = subject.intersect(&ray((-5.0, 0.0, 0.25), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(1.0, 0.0, 1.0).normalize(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::rotate_x(180.0);
+        let subject = Subject::new(transform, false, 1.0, 0.0, 1.0, 360.0);
+        let (t_hit, si) = subject.intersect(&ray((0.3, 0.0, -5.0), (0.0, 0.0, 1.0))).unwrap();
+
+        assert_approx!(t_hit, 4.91, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.3, 0.0, -0.09), epsilon = 1e-5);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.2, 1.0, 270.0);
+
+        let rays = [
+            ray((-5.0, 0.0, 0.25), (1.0, 0.0, 0.0)),
+            ray((0.3, 0.0, 5.0), (0.0, 0.0, -1.0)),
+            ray((0.3, -0.3, 5.0), (0.0, 0.0, -1.0)),
+            ray((0.0, -5.0, 0.5), (0.0, 1.0, 0.0)),
+            ray((0.0, 0.0, 0.5), (1.0, 0.0, 0.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(subject.intersect_p(ray), subject.intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_surface_area_of_the_paraboloid() {
+        let expected = Real::PI / 6.0 * (5.0 * (5.0 as Real).sqrt() - 1.0);
+
+        assert_approx!(paraboloid().area(), expected);
+    }
+
+    #[test]
+    fn it_excludes_the_part_below_z_min() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.5, 1.0, 360.0);
+        let expected = Real::PI / 6.0 * (5.0 * (5.0 as Real).sqrt() - 3.0 * (3.0 as Real).sqrt());
+
+        assert_approx!(subject.area(), expected);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::geometry::{Bounds3f, EFloat, Point2f, Point3f, Ray, Real, Transform, Vector3f};
+use crate::geometry::{gamma, quadratic};
+use crate::interaction::SurfaceInteraction;
+use super::{Shape, azimuth, candidate_hits, normal_derivatives};
+
+// A sphere centered at the object space origin. It can be clipped to the part between two
+// heights and swept around the z axis by less than a full turn, e.g. to make a hemisphere.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    object_to_world: Transform,
+    reverse_orientation: bool,
+    radius: Real,
+    z_min: Real,
+    z_max: Real,
+    theta_min: Real,
+    theta_max: Real,
+    phi_max: Real,
+}
+
+impl Sphere {
+    pub fn new(object_to_world: Transform, reverse_orientation: bool, radius: Real, z_min: Real, z_max: Real, phi_max: Real) -> Self {
+        // max and min rather than clamp, which panics if the radius is NaN.
+        let radius = radius.abs();
+        let (z_min, z_max) = (z_min.min(z_max), z_min.max(z_max));
+        let z_min = z_min.max(-radius).min(radius);
+        let z_max = z_max.max(-radius).min(radius);
+
+        let theta_min = (z_min / radius).clamp(-1.0, 1.0).acos();
+        let theta_max = (z_max / radius).clamp(-1.0, 1.0).acos();
+        let phi_max = phi_max.clamp(0.0, 360.0).to_radians();
+
+        Self { object_to_world, reverse_orientation, radius, z_min, z_max, theta_min, theta_max, phi_max }
+    }
+
+    pub fn full(object_to_world: Transform, reverse_orientation: bool, radius: Real) -> Self {
+        Self::new(object_to_world, reverse_orientation, radius, -radius, radius, 360.0)
+    }
+
+    pub fn radius(&self) -> Real {
+        self.radius
+    }
+
+    // Finds the closest hit in object space that isn't clipped away. Returns the object
+    // space ray since the hit distance is only meaningful along it.
+    fn intersect_object(&self, ray: &Ray) -> Option<(Ray, Real, Point3f, Real)> {
+        let (ray, o_error, d_error) = self.world_to_object().transform_ray_with_error(ray);
+
+        let ox = EFloat::new(ray.o.x(), o_error.x());
+        let oy = EFloat::new(ray.o.y(), o_error.y());
+        let oz = EFloat::new(ray.o.z(), o_error.z());
+        let dx = EFloat::new(ray.d.x(), d_error.x());
+        let dy = EFloat::new(ray.d.y(), d_error.y());
+        let dz = EFloat::new(ray.d.z(), d_error.z());
+        let radius = EFloat::from(self.radius);
+
+        let a = dx * dx + dy * dy + dz * dz;
+        let b = (dx * ox + dy * oy + dz * oz) * 2.0;
+        let c = ox * ox + oy * oy + oz * oz - radius * radius;
+
+        let (t_hit, p_hit, phi) = candidate_hits(&ray, quadratic(a, b, c)?)
+            .find_map(|t| self.hit_point(&ray, t.value()).map(|(p, phi)| (t.value(), p, phi)))?;
+
+        Some((ray, t_hit, p_hit, phi))
+    }
+
+    // Returns the point at t and its angle around the z axis, or None if it's clipped away.
+    fn hit_point(&self, ray: &Ray, t: Real) -> Option<(Point3f, Real)> {
+        let mut p = ray.at(t);
+
+        // Reprojects the point onto the surface to reduce its error.
+        p *= self.radius / p.distance(&Point3f::default());
+
+        if p.x() == 0.0 && p.y() == 0.0 {
+            p[0] = 1e-5 * self.radius;
+        }
+
+        let phi = azimuth(&p);
+
+        let below = self.z_min > -self.radius && p.z() < self.z_min;
+        let above = self.z_max < self.radius && p.z() > self.z_max;
+
+        if below || above || phi > self.phi_max {
+            None
+        } else {
+            Some((p, phi))
+        }
+    }
+}
+
+impl Shape for Sphere {
+    fn object_to_world(&self) -> &Transform {
+        &self.object_to_world
+    }
+
+    fn reverse_orientation(&self) -> bool {
+        self.reverse_orientation
+    }
+
+    fn object_bound(&self) -> Bounds3f {
+        let p_min = Point3f::new(-self.radius, -self.radius, self.z_min);
+        let p_max = Point3f::new(self.radius, self.radius, self.z_max);
+
+        Bounds3f::new(&p_min, &p_max)
+    }
+
+    fn intersect(&self, ray: &Ray) -> Option<(Real, SurfaceInteraction)> {
+        let (ray, t_hit, p, phi) = self.intersect_object(ray)?;
+        let (x, y, z) = (p.x(), p.y(), p.z());
+
+        let theta = (z / self.radius).clamp(-1.0, 1.0).acos();
+        let theta_range = self.theta_max - self.theta_min;
+
+        let u = phi / self.phi_max;
+        let v = (theta - self.theta_min) / theta_range;
+
+        let z_radius = (x * x + y * y).sqrt();
+        let cos_phi = x / z_radius;
+        let sin_phi = y / z_radius;
+
+        let dpdu = Vector3f::new(-self.phi_max * y, self.phi_max * x, 0.0);
+        let dpdv = Vector3f::new(z * cos_phi, z * sin_phi, -self.radius * theta.sin()) * theta_range;
+
+        let d2pduu = Vector3f::new(x, y, 0.0) * (-self.phi_max * self.phi_max);
+        let d2pduv = Vector3f::new(-sin_phi, cos_phi, 0.0) * (theta_range * z * self.phi_max);
+        let d2pdvv = Vector3f::new(x, y, z) * (-theta_range * theta_range);
+
+        let (dndu, dndv) = normal_derivatives(&dpdu, &dpdv, &d2pduu, &d2pduv, &d2pdvv);
+
+        let p_error = Vector3f::from(&p.abs()) * gamma(5);
+        let flip_normal = self.reverse_orientation ^ self.transform_swaps_handedness();
+
+        let si = SurfaceInteraction::new(
+            p, p_error, Point2f::new(u, v), -ray.d, dpdu, dpdv, dndu, dndv, ray.time, flip_normal,
+        );
+
+        Some((t_hit, &self.object_to_world * &si))
+    }
+
+    fn intersect_p(&self, ray: &Ray) -> bool {
+        self.intersect_object(ray).is_some()
+    }
+
+    fn area(&self) -> Real {
+        self.phi_max * self.radius * (self.z_max - self.z_min)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,224 @@
+use super::*;
+use crate::assert_approx;
+use crate::shape::ray;
+use crate::geometry::{Float, Normal3f};
+
+type Subject = Sphere;
+
+fn unit_sphere() -> Subject {
+    Subject::full(Transform::default(), false, 1.0)
+}
+
+mod new {
+    use super::*;
+
+    #[test]
+    fn it_clamps_the_heights_to_the_radius() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -5.0, 5.0, 360.0);
+
+        assert_eq!(subject, unit_sphere());
+    }
+
+    #[test]
+    fn it_accepts_the_heights_in_either_order() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.5, -0.5, 360.0);
+
+        assert_eq!(subject.object_bound().p_min.z(), -0.5);
+        assert_eq!(subject.object_bound().p_max.z(), 0.5);
+    }
+
+    #[test]
+    fn it_uses_the_size_of_a_negative_radius() {
+        let subject = Subject::new(Transform::default(), false, -1.0, -5.0, 5.0, 360.0);
+
+        assert_eq!(subject, unit_sphere());
+    }
+
+    #[test]
+    fn it_does_not_panic_if_the_radius_is_nan() {
+        let subject = Subject::new(Transform::default(), false, Real::NAN, -1.0, 1.0, 360.0);
+
+        assert!(subject.radius().is_nan());
+    }
+}
+
+mod bounds {
+    use super::*;
+
+    #[test]
+    fn it_bounds_the_sphere_in_object_space() {
+        let subject = Subject::new(Transform::default(), false, 2.0, -1.0, 2.0, 360.0);
+        let bounds = subject.object_bound();
+
+        assert_eq!(bounds.p_min, Point3f::new(-2.0, -2.0, -1.0));
+        assert_eq!(bounds.p_max, Point3f::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn it_bounds_the_sphere_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 0.0, 10.0));
+        let subject = Subject::full(transform, false, 1.0);
+        let bounds = subject.world_bound();
+
+        assert_approx!(bounds.p_min, Point3f::new(-1.0, -1.0, 9.0));
+        assert_approx!(bounds.p_max, Point3f::new(1.0, 1.0, 11.0));
+    }
+}
+
+mod intersect {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_distance_to_the_closest_hit() {
+        let (t_hit, si) = unit_sphere().intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_describes_the_surface_at_the_hit_point() {
+        let (_, si) = unit_sphere().intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+        assert_approx!(si.uv, Point2f::new(0.5, 0.5), epsilon = 1e-5);
+        assert_approx!(si.wo, Vector3f::new(-1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn it_computes_how_the_normal_changes_over_the_surface() {
+        let (_, si) = unit_sphere().intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        // The normal of a unit sphere is the hit point, so its derivatives match the point's.
+        assert_approx!(Vector3f::from(&si.dndu), si.dpdu, epsilon = 1e-5);
+        assert_approx!(Vector3f::from(&si.dndv), si.dpdv, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_bounds_the_error_of_the_hit_point() {
+        let (_, si) = unit_sphere().intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        let x = -(1.0 - 0.3 * 0.3 - 0.2 * 0.2 as Real).sqrt();
+
+        assert!((si.p.x() - x).abs() <= si.p_error.x());
+        assert!(si.p_error.x() > 0.0);
+    }
+
+    #[test]
+    fn it_returns_none_if_the_ray_misses() {
+        assert!(unit_sphere().intersect(&ray((-5.0, 2.0, 0.0), (1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_sphere_is_behind_the_ray() {
+        assert!(unit_sphere().intersect(&ray((-5.0, 0.0, 0.0), (-1.0, 0.0, 0.0))).is_none());
+    }
+
+    #[test]
+    fn it_returns_none_if_the_hit_is_beyond_t_max() {
+        let ray = Ray { t_max: 3.0, ..ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)) };
+
+        assert!(unit_sphere().intersect(&ray).is_none());
+    }
+
+    #[test]
+    fn it_hits_the_far_side_if_the_ray_starts_inside() {
+        let (t_hit, si) = unit_sphere().intersect(&ray((0.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 1.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_ignores_hits_that_are_clipped_by_the_heights() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -1.0, 0.5, 360.0);
+
+        assert!(subject.intersect(&ray((-5.0, 0.0, 0.8), (1.0, 0.0, 0.0))).is_none());
+        assert!(subject.intersect(&ray((-5.0, 0.0, 0.4), (1.0, 0.0, 0.0))).is_some());
+    }
+
+    #[test]
+    fn it_uses_the_far_hit_if_the_near_one_is_outside_the_sweep() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -1.0, 1.0, 180.0);
+        let (t_hit, si) = subject.intersect(&ray((0.0, -5.0, 0.0), (0.0, 1.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 6.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(0.0, 1.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_flips_the_normal_if_the_orientation_is_reversed() {
+        let subject = Subject::full(Transform::default(), true, 1.0);
+        let (_, si) = subject.intersect(&ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(si.n, Normal3f::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_returns_the_hit_in_world_space() {
+        let transform = Transform::translate(&Vector3f::new(0.0, 0.0, 10.0));
+        let subject = Subject::full(transform, false, 1.0);
+        let (t_hit, si) = subject.intersect(&ray((-5.0, 0.0, 10.0), (1.0, 0.0, 0.0))).unwrap();
+
+        assert_approx!(t_hit, 4.0, epsilon = 1e-5);
+        assert_approx!(si.p, Point3f::new(-1.0, 0.0, 10.0), epsilon = 1e-5);
+        assert_approx!(si.n, Normal3f::new(-1.0, 0.0, 0.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn it_keeps_the_normal_on_the_side_of_the_partial_derivatives_if_the_transform_swaps_handedness() {
+        let subject = Subject::full(Transform::scale(-1.0, 1.0, 1.0), false, 1.0);
+        let (_, si) = subject.intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        assert!(si.n.dot(&si.dpdu.cross(&si.dpdv)) > 0.0);
+    }
+
+    #[test]
+    fn it_spawns_rays_that_leave_the_surface_without_hitting_it_again() {
+        let subject = unit_sphere();
+        let (_, si) = subject.intersect(&ray((-5.0, 0.3, 0.2), (1.0, 0.0, 0.0))).unwrap();
+
+        let outwards = si.spawn_ray(&Vector3f::from(&si.n));
+        let (t_hit, _) = subject.intersect(&si.spawn_ray(&-Vector3f::from(&si.n))).unwrap();
+
+        assert!(!subject.intersect_p(&outwards));
+        assert!(t_hit > 1.0);
+    }
+}
+
+mod intersect_p {
+    use super::*;
+
+    #[test]
+    fn it_agrees_with_intersect() {
+        let subject = Subject::new(Transform::default(), false, 1.0, -0.5, 0.5, 270.0);
+
+        let rays = [
+            ray((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)),
+            ray((-5.0, 0.0, 0.8), (1.0, 0.0, 0.0)),
+            ray((0.0, -5.0, 0.0), (0.0, 1.0, 0.0)),
+            ray((5.0, -0.5, 0.0), (-1.0, 0.0, 0.0)),
+            ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)),
+        ];
+
+        for ray in rays.iter() {
+            assert_eq!(subject.intersect_p(ray), subject.intersect(ray).is_some());
+        }
+    }
+}
+
+mod area {
+    use super::*;
+
+    #[test]
+    fn it_returns_the_surface_area_of_a_full_sphere() {
+        assert_approx!(Subject::full(Transform::default(), false, 2.0).area(), 16.0 * Real::PI);
+    }
+
+    #[test]
+    fn it_returns_the_surface_area_of_a_partial_sphere() {
+        let subject = Subject::new(Transform::default(), false, 1.0, 0.0, 1.0, 180.0);
+
+        assert_approx!(subject.area(), Real::PI);
+    }
+}